@@ -0,0 +1,56 @@
+//! Registers every `src/years/y{year}/day{day}.rs`, see `days::solver`.
+
+use std::fmt::Write;
+use std::path::Path;
+
+/// The numbers in the names of the entries of `dir` that are `{prefix}{number}{suffix}`, sorted.
+fn numbered(dir: &Path, prefix: &str, suffix: &str) -> Vec<u32> {
+    let mut numbers = std::fs::read_dir(dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let name = entry.unwrap().file_name().into_string().ok()?;
+            name.strip_prefix(prefix)?
+                .strip_suffix(suffix)?
+                .parse::<u32>()
+                .ok()
+        })
+        .collect::<Vec<_>>();
+    numbers.sort();
+    numbers
+}
+
+fn main() {
+    let years_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/years");
+    println!("cargo:rerun-if-changed={}", years_dir.display());
+    let mut modules = String::new();
+    let mut solvers = Vec::new();
+    for year in numbered(&years_dir, "y", "") {
+        let dir = years_dir.join(format!("y{year}"));
+        println!("cargo:rerun-if-changed={}", dir.display());
+        writeln!(
+            modules,
+            "pub mod y{year} {{\n    use super::{{Answer, Solution}};"
+        )
+        .unwrap();
+        for day in numbered(&dir, "day", ".rs") {
+            let path = dir.join(format!("day{day}.rs"));
+            writeln!(
+                modules,
+                "    #[path = {:?}]\n    pub mod day{day};",
+                path.display().to_string()
+            )
+            .unwrap();
+            solvers.push(format!(
+                "    ({year}, {day}, &y{year}::day{day}::Day{day}),"
+            ));
+        }
+        writeln!(modules, "}}").unwrap();
+    }
+    let registry = format!(
+        "{modules}\n/// Every solution by year and day, sorted.\n\
+         pub static SOLVERS: &[(u32, u32, &dyn Solver)] = &[\n{}\n];\n",
+        solvers.join("\n")
+    );
+    let out = Path::new(&std::env::var("OUT_DIR").unwrap()).join("registry.rs");
+    std::fs::write(out, registry).unwrap();
+}
@@ -0,0 +1,6 @@
+1721
+979
+366
+299
+675
+1456
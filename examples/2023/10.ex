@@ -0,0 +1,5 @@
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
@@ -0,0 +1,2 @@
+Time:      7  15   30
+Distance:  9  40  200
@@ -0,0 +1,111 @@
+use crate::cli::{BenchArgs, DaySelection};
+use crate::table::print_table;
+use aoc2023::{InputManager, Overrides, Part, Timings};
+use itertools::Itertools;
+use std::time::Duration;
+
+/// Min, median and mean of repeated measurements.
+#[derive(Debug, Clone, Copy)]
+struct Stats {
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+}
+
+impl Stats {
+    fn from_samples(samples: impl Iterator<Item = Duration>) -> Self {
+        let samples = samples.sorted().collect_vec();
+        let n = samples.len();
+        let median = match n % 2 {
+            0 => (samples[n / 2 - 1] + samples[n / 2]) / 2,
+            _ => samples[n / 2],
+        };
+        Self {
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / n as u32,
+        }
+    }
+}
+
+struct DayBench {
+    day: u32,
+    parse: Stats,
+    part1: Stats,
+    part2: Stats,
+    total: Stats,
+}
+
+pub fn format_duration(d: Duration) -> String {
+    match d.as_secs_f64() {
+        s if s >= 1.0 => format!("{s:.2}s"),
+        s if s >= 1e-3 => format!("{:.2}ms", s * 1e3),
+        s => format!("{:.2}µs", s * 1e6),
+    }
+}
+
+fn format_stats(stats: &Stats) -> String {
+    format!(
+        "{} / {} / {}",
+        format_duration(stats.min),
+        format_duration(stats.median),
+        format_duration(stats.mean)
+    )
+}
+
+fn bench_day(year: u32, day: u32, input: &str, runs: usize) -> anyhow::Result<DayBench> {
+    let timings = (0..runs)
+        .map(|_| {
+            let solved =
+                aoc2023::solve_day(year, day, input.trim(), Part::Both, &Overrides::default())?;
+            match solved.failed.first() {
+                Some((part, panic)) => anyhow::bail!("day {day} part {part} {panic}"),
+                None => Ok(solved.timings),
+            }
+        })
+        .collect::<anyhow::Result<Vec<Timings>>>()?;
+    Ok(DayBench {
+        day,
+        parse: Stats::from_samples(timings.iter().map(|t| t.parse)),
+        part1: Stats::from_samples(timings.iter().map(|t| t.part1)),
+        part2: Stats::from_samples(timings.iter().map(|t| t.part2)),
+        total: Stats::from_samples(timings.iter().map(|t| t.total())),
+    })
+}
+
+/// Times parsing and both parts of every selected day, slowest day first.
+pub fn bench(args: &BenchArgs, inputs: &InputManager) -> anyhow::Result<()> {
+    anyhow::ensure!(args.runs > 0, "--runs must be at least 1");
+    let days = args
+        .day
+        .as_ref()
+        .unwrap_or(&DaySelection::All)
+        .days(&aoc2023::solved_days(args.year));
+    inputs.prefetch(args.year, &days)?;
+    let mut results = Vec::new();
+    for day in days {
+        eprintln!("benchmarking day {day}..");
+        let input = inputs.get(args.year, day)?;
+        results.push(bench_day(args.year, day, &input, args.runs)?);
+    }
+    results.sort_by_key(|r| std::cmp::Reverse(r.total.median));
+
+    let header = ["day", "parse", "part 1", "part 2", "total"];
+    let rows = results
+        .iter()
+        .map(|r| {
+            vec![
+                r.day.to_string(),
+                format_stats(&r.parse),
+                format_stats(&r.part1),
+                format_stats(&r.part2),
+                format_stats(&r.total),
+            ]
+        })
+        .collect_vec();
+    println!("{} runs per day, min / median / mean", args.runs);
+    print_table(&header, &rows);
+    let total = results.iter().map(|r| r.total.median).sum::<Duration>();
+    println!("total (sum of medians): {}", format_duration(total));
+    Ok(())
+}
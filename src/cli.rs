@@ -0,0 +1,334 @@
+use crate::report::Format;
+use aoc2023::{Overrides, Part, DEFAULT_BASE_URL, PROJECT_ROOT};
+use clap::builder::{BoolishValueParser, PossibleValuesParser, TypedValueParser};
+use clap::error::ErrorKind;
+use clap::parser::ValueSource;
+use clap::{ArgMatches, Args, Parser, Subcommand};
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Parser, Debug)]
+#[command(about = "Advent of Code solution runner")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    #[command(flatten)]
+    pub run: RunArgs,
+
+    /// Never download, fail when an input isn't cached
+    #[arg(long, global = true, env = "AOC_OFFLINE", value_parser = BoolishValueParser::new())]
+    pub offline: bool,
+
+    /// Input cache directory, defaults to `input/` in the project root. Profiles use a
+    /// subdirectory of `AOC_INPUT_DIR` or the default, this flag is used as is
+    #[arg(long, global = true)]
+    pub input_dir: Option<PathBuf>,
+
+    /// Session profile, each profile has its own session, input cache and answer store
+    #[arg(long, global = true, env = "AOC_PROFILE", value_parser = parse_profile)]
+    pub profile: Option<String>,
+
+    /// Advent of Code website to download from
+    #[arg(long, global = true, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    pub base_url: String,
+}
+
+impl Cli {
+    /// Fails on flags of the default `run` in front of a subcommand, which would be ignored.
+    /// clap's `args_conflicts_with_subcommands` would reject the global flags too.
+    pub fn check_run_flags(command: &mut clap::Command, matches: &ArgMatches) {
+        let Some((name, _)) = matches.subcommand() else {
+            return;
+        };
+        let run = RunArgs::augment_args(clap::Command::new("run"));
+        for arg in run.get_arguments() {
+            if matches.value_source(arg.get_id().as_str()) == Some(ValueSource::CommandLine) {
+                let flag = arg.get_long().unwrap_or(arg.get_id().as_str());
+                command
+                    .error(
+                        ErrorKind::ArgumentConflict,
+                        format!("--{flag} can't be used with `{name}`, pass it after `{name}`"),
+                    )
+                    .exit();
+            }
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Solve one day, a range of days or all days
+    Run(RunArgs),
+    /// Check the answers of every day against the answer store
+    Verify(VerifyArgs),
+    /// Solve the examples of every day and check their expected answers
+    Examples(ExamplesArgs),
+    /// Create the module, example and test stub of a day that has no solution yet
+    NewDay(NewDayArgs),
+    /// Save the examples and example answers of a puzzle description into the example store
+    Extract(ExtractArgs),
+    /// Time parsing and both parts of every day on the cached inputs
+    Bench(BenchArgs),
+    /// Show the solutions, answers, inputs and examples of every year
+    Summary,
+    /// Download the inputs of a whole year (or some days) into the input cache
+    Fetch(FetchArgs),
+    /// Submit an answer to the website, solving the day first when no answer is given
+    Submit(SubmitArgs),
+    /// Re-run a day's examples and real input whenever its source, input or examples change
+    Watch(WatchArgs),
+    /// Download and solve the puzzle that unlocked today, or wait for the next one
+    Today(TodayArgs),
+    /// Check the session with the website and show which account it belongs to
+    Whoami,
+}
+
+#[derive(Args, Debug)]
+pub struct RunArgs {
+    /// Puzzle year
+    #[arg(short, long, default_value_t = 2023)]
+    pub year: u32,
+
+    /// Day to solve: a single day (`5`), a range (`3-7`) or `all`, defaults to the latest day
+    #[arg(short, long)]
+    pub day: Option<DaySelection>,
+
+    /// Which part(s) to solve
+    #[arg(short, long, value_parser = part_parser(), default_value = "both")]
+    pub part: Part,
+
+    /// Use the example input `examples/{year}/{day}.ex` instead of the real input
+    #[arg(short, long, conflicts_with = "input")]
+    pub example: bool,
+
+    /// Read the input from this file instead of the input cache, `-` reads stdin
+    #[arg(short, long)]
+    pub input: Option<PathBuf>,
+
+    /// Threads to solve with, days run concurrently when there's more than one, 0 uses every core
+    #[arg(short, long, default_value_t = 0)]
+    pub jobs: usize,
+
+    /// How to print the results
+    #[arg(long, value_enum, default_value_t = Format::Table)]
+    pub format: Format,
+
+    /// Give up on a part after this many seconds and report it as TIMEOUT
+    #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    pub timeout: Option<Duration>,
+
+    /// Solve with another value of one of the day's parameters, e.g. `--param steps1=6`
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = Overrides::parse_arg)]
+    pub params: Vec<(String, String)>,
+}
+
+#[derive(Args, Debug)]
+pub struct VerifyArgs {
+    /// Puzzle year
+    #[arg(short, long, default_value_t = 2023)]
+    pub year: u32,
+
+    /// Days to verify, defaults to all days
+    #[arg(short, long)]
+    pub day: Option<DaySelection>,
+
+    /// Record answers that aren't in the answer store yet as correct, unless they were rejected before
+    #[arg(long)]
+    pub record: bool,
+
+    /// Give up on a part after this many seconds and report it as TIMEOUT
+    #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    pub timeout: Option<Duration>,
+}
+
+#[derive(Args, Debug)]
+pub struct ExamplesArgs {
+    /// Puzzle year
+    #[arg(short, long, default_value_t = 2023)]
+    pub year: u32,
+
+    /// Days to check, defaults to all days
+    #[arg(short, long)]
+    pub day: Option<DaySelection>,
+}
+
+#[derive(Args, Debug)]
+pub struct WatchArgs {
+    /// Puzzle year
+    #[arg(short, long, default_value_t = 2023)]
+    pub year: u32,
+
+    /// Day to watch, defaults to the latest day
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    pub day: Option<u32>,
+
+    /// Milliseconds between checks for changed files
+    #[arg(long, default_value_t = 500)]
+    pub interval: u64,
+}
+
+#[derive(Args, Debug)]
+pub struct TodayArgs {
+    /// Count down to the next puzzle's unlock and solve it once it's out
+    #[arg(short, long)]
+    pub wait: bool,
+
+    /// Solve this puzzle instead, e.g. `2023-5`, with `--wait` once it unlocks
+    #[arg(long, value_name = "YEAR-DAY", value_parser = parse_puzzle)]
+    pub puzzle: Option<(u32, u32)>,
+}
+
+#[derive(Args, Debug)]
+pub struct NewDayArgs {
+    /// Puzzle year
+    #[arg(short, long, default_value_t = 2023)]
+    pub year: u32,
+
+    /// Day to create
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    pub day: u32,
+
+    /// Crate to add the day to
+    #[arg(long, default_value = PROJECT_ROOT)]
+    pub root: PathBuf,
+}
+
+#[derive(Args, Debug)]
+pub struct ExtractArgs {
+    /// Puzzle year
+    #[arg(short, long, default_value_t = 2023)]
+    pub year: u32,
+
+    /// Day of the puzzle
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    pub day: u32,
+
+    /// Saved puzzle page to read instead of downloading it
+    #[arg(long)]
+    pub page: Option<PathBuf>,
+
+    /// Overwrite examples that already exist
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct BenchArgs {
+    /// Puzzle year
+    #[arg(short, long, default_value_t = 2023)]
+    pub year: u32,
+
+    /// Days to benchmark, defaults to all days
+    #[arg(short, long)]
+    pub day: Option<DaySelection>,
+
+    /// How many times each day is solved
+    #[arg(short, long, default_value_t = 5)]
+    pub runs: usize,
+}
+
+#[derive(Args, Debug)]
+pub struct FetchArgs {
+    /// Puzzle year
+    #[arg(short, long, default_value_t = 2023)]
+    pub year: u32,
+
+    /// Days to fetch, defaults to all 25 days
+    #[arg(short, long)]
+    pub day: Option<DaySelection>,
+}
+
+#[derive(Args, Debug)]
+pub struct SubmitArgs {
+    /// Puzzle year
+    #[arg(short, long, default_value_t = 2023)]
+    pub year: u32,
+
+    /// Day to submit
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    pub day: u32,
+
+    /// Part to submit
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: u8,
+
+    /// Answer to submit, defaults to the day's answer on the real input
+    pub answer: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DaySelection {
+    All,
+    Range(RangeInclusive<u32>),
+}
+
+impl DaySelection {
+    /// The selected days out of `all`, days of a range that aren't in `all` are left out.
+    pub fn days(&self, all: &[u32]) -> Vec<u32> {
+        match self {
+            DaySelection::All => all.to_vec(),
+            DaySelection::Range(r) => all.iter().copied().filter(|d| r.contains(d)).collect(),
+        }
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |d: &str| match d.trim().parse::<u32>() {
+            Ok(d @ 1..=25) => Ok(d),
+            _ => Err(format!("`{d}` is not a day between 1 and 25")),
+        };
+        let s = s.trim();
+        if s == "all" {
+            return Ok(DaySelection::All);
+        }
+        if let Some((from, to)) = s.split_once("..=").or(s.split_once('-')) {
+            let (from, to) = (parse_day(from)?, parse_day(to)?);
+            if from > to {
+                return Err(format!("`{s}` is an empty range"));
+            }
+            return Ok(DaySelection::Range(from..=to));
+        }
+        parse_day(s).map(|d| DaySelection::Range(d..=d))
+    }
+}
+
+fn parse_profile(s: &str) -> Result<String, String> {
+    match s
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        true => Ok(s.to_string()),
+        false => Err(format!(
+            "`{s}` isn't a profile name, use letters, digits, `-` and `_`"
+        )),
+    }
+}
+
+fn part_parser() -> impl TypedValueParser<Value = Part> {
+    PossibleValuesParser::new(["1", "2", "both"]).map(|part| match part.as_str() {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => Part::Both,
+    })
+}
+
+fn parse_puzzle(s: &str) -> Result<(u32, u32), String> {
+    let puzzle = s
+        .split_once('-')
+        .and_then(|(year, day)| Some((year.parse().ok()?, day.parse().ok()?)));
+    match puzzle {
+        Some((year, day)) if (1..=25).contains(&day) => Ok((year, day)),
+        _ => Err(format!("`{s}` isn't a puzzle like 2023-5")),
+    }
+}
+
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    let seconds = s.parse::<f64>().map_err(|err| err.to_string())?;
+    Duration::try_from_secs_f64(seconds).map_err(|err| err.to_string())
+}
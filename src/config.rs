@@ -0,0 +1,128 @@
+use crate::cli::Cli;
+use crate::report::Format;
+use anyhow::Context;
+use aoc2023::{ANSWER_DIR_VAR, INPUT_DIR_VAR, PROJECT_ROOT};
+use clap::{Command, FromArgMatches, ValueEnum};
+use reqwest::header::HeaderValue;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+/// Overrides the config file, defaults to `aoc.toml` in the project root. Empty means no config.
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+/// Runner defaults from `aoc.toml`, command line flags and `AOC_*` variables take precedence.
+///
+/// ```toml
+/// year = 2023
+/// input_dir = "input"
+/// answer_dir = "answers"
+/// user_agent = "github.com/me/aoc by me@example.com"
+/// jobs = 4
+/// timeout = 10.0
+/// format = "table"
+/// ```
+#[derive(Deserialize, Default, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub year: Option<u32>,
+    /// Relative to the directory of the config file
+    pub input_dir: Option<PathBuf>,
+    /// Relative to the directory of the config file
+    pub answer_dir: Option<PathBuf>,
+    pub user_agent: Option<String>,
+    pub jobs: Option<usize>,
+    /// Seconds
+    pub timeout: Option<f64>,
+    pub format: Option<Format>,
+}
+
+impl Config {
+    /// Reads the config file, a missing `aoc.toml` in the project root is an empty config.
+    pub fn load() -> anyhow::Result<Self> {
+        let (path, required) = match std::env::var_os(CONFIG_VAR) {
+            Some(path) if path.is_empty() => return Ok(Self::default()),
+            Some(path) => (PathBuf::from(path), true),
+            None => (Path::new(PROJECT_ROOT).join("aoc.toml"), false),
+        };
+        let content = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound && !required => {
+                return Ok(Self::default())
+            }
+            Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display()))?,
+        };
+        let mut config: Config = toml::from_str(&content)
+            .with_context(|| format!("failed to parse {}", path.display()))?;
+        if config.timeout.is_some_and(|t| !(t.is_finite() && t >= 0.0)) {
+            anyhow::bail!("{}: timeout must be a number of seconds", path.display());
+        }
+        if let Some(user_agent) = &config.user_agent {
+            if HeaderValue::from_str(user_agent).is_err() {
+                anyhow::bail!(
+                    "{}: user_agent {user_agent:?} can't be sent as a header",
+                    path.display()
+                );
+            }
+        }
+        let base = path.parent().unwrap_or(Path::new(""));
+        for dir in [&mut config.input_dir, &mut config.answer_dir] {
+            *dir = dir.take().map(|dir| base.join(dir));
+        }
+        Ok(config)
+    }
+
+    /// Uses the config values as defaults of the flags with the same name, in every command.
+    fn defaults(&self, mut command: Command) -> Command {
+        let defaults = [
+            ("year", self.year.map(|year| year.to_string())),
+            ("jobs", self.jobs.map(|jobs| jobs.to_string())),
+            ("timeout", self.timeout.map(|timeout| timeout.to_string())),
+            (
+                "format",
+                self.format
+                    .and_then(|f| f.to_possible_value())
+                    .map(|f| f.get_name().to_string()),
+            ),
+        ];
+        for (id, value) in defaults {
+            let Some(value) = value else { continue };
+            if command.get_arguments().any(|arg| arg.get_id() == id) {
+                command = command.mut_arg(id, |arg| arg.default_value(value));
+            }
+        }
+        let subcommands = command
+            .get_subcommands()
+            .map(|sub| sub.get_name().to_string())
+            .collect::<Vec<_>>();
+        for name in subcommands {
+            command = command.mut_subcommand(name, |sub| self.defaults(sub));
+        }
+        command
+    }
+
+    /// Parses the command line with the config's defaults.
+    pub fn parse(&self) -> Cli {
+        let mut command = self.defaults(<Cli as clap::CommandFactory>::command());
+        let matches = command.clone().get_matches();
+        Cli::check_run_flags(&mut command, &matches);
+        Cli::from_arg_matches(&matches).unwrap_or_else(|err| err.exit())
+    }
+
+    /// The input directory: `AOC_INPUT_DIR`, the configured one or `input/` in the project root.
+    pub fn input_dir(&self) -> PathBuf {
+        dir(INPUT_DIR_VAR, &self.input_dir, "input")
+    }
+
+    /// The answer directory: `AOC_ANSWER_DIR`, the configured one or `answers/` in the project
+    /// root.
+    pub fn answer_dir(&self) -> PathBuf {
+        dir(ANSWER_DIR_VAR, &self.answer_dir, "answers")
+    }
+}
+
+fn dir(var: &str, configured: &Option<PathBuf>, default: &str) -> PathBuf {
+    std::env::var_os(var)
+        .map(PathBuf::from)
+        .or_else(|| configured.clone())
+        .unwrap_or_else(|| Path::new(PROJECT_ROOT).join(default))
+}
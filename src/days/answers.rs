@@ -0,0 +1,106 @@
+use super::aoc_util::{profile, profile_dir, PROJECT_ROOT};
+use super::Answer;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Overrides the answer directory, defaults to `answers/` in the project root.
+pub const ANSWER_DIR_VAR: &str = "AOC_ANSWER_DIR";
+
+/// The answer directory, or the profile's subdirectory of it.
+pub fn answer_dir() -> PathBuf {
+    let dir = std::env::var_os(ANSWER_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(PROJECT_ROOT).join("answers"));
+    profile_dir(dir, profile().as_deref())
+}
+
+#[derive(Serialize, Deserialize, Default, Debug)]
+struct DayAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+/// Known-correct answers of a year, kept in `{answer_dir}/{year}.toml`.
+#[derive(Debug)]
+pub struct AnswerStore {
+    path: PathBuf,
+    days: BTreeMap<String, DayAnswers>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { .. } => write!(f, "FAIL"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+impl AnswerStore {
+    /// The store of `year` in [`answer_dir`].
+    pub fn load(year: u32) -> anyhow::Result<Self> {
+        Self::open(&answer_dir(), year)
+    }
+
+    /// The store of `year` in `dir`.
+    pub fn open(dir: &Path, year: u32) -> anyhow::Result<Self> {
+        let path = dir.join(format!("{year}.toml"));
+        let days = match std::fs::read_to_string(&path) {
+            Ok(content) => toml::from_str(&content)
+                .with_context(|| format!("failed to parse {}", path.display()))?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display()))?,
+        };
+        Ok(Self { path, days })
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&self.path, toml::to_string(&self.days)?)
+            .with_context(|| format!("failed to write {}", self.path.display()))
+    }
+
+    pub fn expected(&self, day: u32, part: u8) -> Option<&str> {
+        let answers = self.days.get(&format!("day{day}"))?;
+        match part {
+            1 => answers.part1.as_deref(),
+            _ => answers.part2.as_deref(),
+        }
+    }
+
+    pub fn verify(&self, day: u32, part: u8, answer: &Answer) -> Verdict {
+        match self.expected(day, part) {
+            Some(expected) if expected == answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    /// Stores `answer` as the correct answer, parts without an answer are never recorded.
+    pub fn record(&mut self, day: u32, part: u8, answer: &Answer) {
+        if *answer == Answer::None {
+            return;
+        }
+        let answers = self.days.entry(format!("day{day}")).or_default();
+        let slot = match part {
+            1 => &mut answers.part1,
+            _ => &mut answers.part2,
+        };
+        *slot = Some(answer.to_string());
+    }
+}
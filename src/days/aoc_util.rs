@@ -1,72 +1,517 @@
-use core::panic;
-use reqwest::blocking::Client;
-use reqwest::header::{HeaderMap, HeaderValue, COOKIE};
+use super::clock::Clock;
+use itertools::Itertools;
+use regex::Regex;
+use reqwest::blocking::{Client, RequestBuilder};
+use reqwest::header::{HeaderMap, HeaderValue, COOKIE, USER_AGENT};
 use reqwest::redirect::Policy;
-use std::fs::OpenOptions;
-use std::io::{Read, Write};
-
-const PROJECT_ROOT: &str = env!("CARGO_MANIFEST_DIR");
-
-fn load_session() -> Result<HeaderValue, Box<dyn std::error::Error>> {
-    let session_path = std::path::Path::new(PROJECT_ROOT).join(".aoc_session");
-    if !session_path.exists() {
-        panic!("no .aoc_session file found, can't download the input")
-    }
-    let session = std::fs::read_to_string(session_path)?;
-    Ok(HeaderValue::from_str(&format!(
-        "session={}",
-        session.trim()
-    ))?)
-}
-
-fn download_input(year: u32, day: u32, path: &std::path::PathBuf) {
-    let cookie_value = load_session().unwrap();
-
-    let mut headers = HeaderMap::new();
-    headers.insert(COOKIE, cookie_value);
-    let client = Client::builder()
-        .default_headers(headers)
-        .redirect(Policy::none())
-        .build()
-        .unwrap();
-
-    let input = client
-        .get(format!("https://adventofcode.com/{year}/day/{day}/input"))
-        .send()
-        .and_then(|response| response.error_for_status())
-        .and_then(|response| response.text())
-        .map_err(|err| err.to_string())
-        .unwrap();
-
-    let mut file = OpenOptions::new();
-    file.create(true);
-    file.write(true)
-        .open(path)
-        .map_err(|err| format!("Failed to create file: {}", err))
-        .unwrap()
-        .write(input.as_bytes())
-        .map_err(|err| format!("Failed to write to file: {}", err))
-        .unwrap();
-}
-
-pub fn get_input(year: u32, day: u32, ex: bool) -> String {
-    let mut filename = format!("{year}-{day}.in");
-    if ex {
-        filename = format!("{year}-{day}.ex")
-    }
-    let path = std::path::Path::new(PROJECT_ROOT)
-        .join("input")
-        .join(&filename);
-    let mut input = String::new();
-    if !path.exists() {
-        if ex {
-            panic!("tried to load example, but it doens't live: {filename}")
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub const PROJECT_ROOT: &str = env!("CARGO_MANIFEST_DIR");
+
+/// Overrides the input cache directory, defaults to `input/` in the project root.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Session cookie value, takes precedence over the session files.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Selects a named profile with its own session, input cache and answer store.
+pub const PROFILE_VAR: &str = "AOC_PROFILE";
+
+/// Overrides the user config directory that keeps the session files.
+pub const CONFIG_DIR_VAR: &str = "AOC_CONFIG_DIR";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Attempts at downloading a freshly unlocked input.
+const UNLOCK_RETRIES: u32 = 10;
+
+#[derive(Debug)]
+pub enum AocError {
+    /// Inputs that aren't cached and can't be downloaded, e.g. in offline mode.
+    MissingInputs {
+        year: u32,
+        days: Vec<u32>,
+        dir: PathBuf,
+    },
+    MissingExample(PathBuf),
+    /// No session in `AOC_SESSION` nor in any of the session files.
+    NoSession(Vec<PathBuf>),
+    /// The puzzle exists but hasn't unlocked yet.
+    NotUnlocked {
+        year: u32,
+        day: u32,
+    },
+    /// The session cookie was rejected, or we got the login page instead of the input.
+    InvalidSession,
+    RateLimited,
+    /// The user agent can't be sent as a header, e.g. because it has a line break.
+    InvalidUserAgent(String),
+    NetworkUnavailable(String),
+    UnexpectedStatus {
+        status: u16,
+        url: String,
+    },
+    /// A page that doesn't look like any of the known answer responses.
+    UnrecognizedResponse(String),
+    Io(std::io::Error),
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::MissingInputs { year, days, dir } => write!(
+                f,
+                "missing inputs for {year} day {} in {} (offline mode)",
+                days.iter().join(", "),
+                dir.display()
+            ),
+            AocError::MissingExample(path) => {
+                write!(
+                    f,
+                    "tried to load example, but it doesn't exist: {}",
+                    path.display()
+                )
+            }
+            AocError::NoSession(paths) => write!(
+                f,
+                "no session found, set {SESSION_VAR} or put it in {}",
+                paths.iter().map(|p| p.display()).join(" or ")
+            ),
+            AocError::NotUnlocked { year, day } => {
+                write!(f, "{year} day {day} is not unlocked yet")
+            }
+            AocError::InvalidSession => write!(
+                f,
+                "the session cookie is expired or invalid, log in again and update it"
+            ),
+            AocError::RateLimited => {
+                write!(f, "rate limited by the server, wait a bit before retrying")
+            }
+            AocError::InvalidUserAgent(user_agent) => {
+                write!(f, "invalid user agent {user_agent:?}")
+            }
+            AocError::NetworkUnavailable(reason) => {
+                write!(f, "network unavailable: {reason}")
+            }
+            AocError::UnexpectedStatus { status, url } => {
+                write!(f, "unexpected response {status} from {url}")
+            }
+            AocError::UnrecognizedResponse(text) => {
+                write!(f, "unrecognized response from the server: {text}")
+            }
+            AocError::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+impl From<std::io::Error> for AocError {
+    fn from(err: std::io::Error) -> Self {
+        AocError::Io(err)
+    }
+}
+
+/// The profile selected in `AOC_PROFILE`, `None` for the default one.
+pub fn profile() -> Option<String> {
+    named_profile(std::env::var(PROFILE_VAR).ok().as_deref())
+}
+
+/// `None` for the default profile, however it's spelled.
+fn named_profile(profile: Option<&str>) -> Option<String> {
+    profile
+        .filter(|profile| !profile.is_empty() && *profile != "default")
+        .map(String::from)
+}
+
+/// `aoc/` in the platform's user config directory, e.g. `~/.config/aoc`.
+pub fn config_dir() -> PathBuf {
+    if let Some(dir) = std::env::var_os(CONFIG_DIR_VAR) {
+        return dir.into();
+    }
+    let var = |name| std::env::var_os(name).map(PathBuf::from);
+    var("XDG_CONFIG_HOME")
+        .or_else(|| var("APPDATA"))
+        .or_else(|| var("HOME").map(|home| home.join(".config")))
+        .unwrap_or_else(|| PathBuf::from(PROJECT_ROOT))
+        .join("aoc")
+}
+
+/// `dir` for the default profile, its subdirectory named after the profile otherwise, so accounts
+/// never share inputs or answers.
+pub fn profile_dir(dir: PathBuf, profile: Option<&str>) -> PathBuf {
+    match profile {
+        Some(profile) => dir.join(profile),
+        None => dir,
+    }
+}
+
+/// Files the session is read from when `AOC_SESSION` isn't set, the first one that exists wins.
+/// The default profile uses `.aoc_session` in the project root or `session` in the config
+/// directory, named profiles `profiles/{profile}.session` in the config directory.
+pub fn session_files(profile: Option<&str>) -> Vec<PathBuf> {
+    match profile {
+        Some(profile) => vec![config_dir()
+            .join("profiles")
+            .join(format!("{profile}.session"))],
+        None => vec![
+            Path::new(PROJECT_ROOT).join(".aoc_session"),
+            config_dir().join("session"),
+        ],
+    }
+}
+
+/// `AOC_SESSION`, or the session in the files of `profile`.
+fn load_session(profile: Option<&str>) -> Result<String, AocError> {
+    match std::env::var(SESSION_VAR) {
+        Ok(session) => Ok(session),
+        Err(_) => {
+            let files = session_files(profile);
+            match files.iter().find(|path| path.exists()) {
+                Some(path) => Ok(std::fs::read_to_string(path)?),
+                None => Err(AocError::NoSession(files)),
+            }
+        }
+    }
+}
+
+/// Talks to the Advent of Code website, or anything serving the same endpoints.
+#[derive(Debug, Clone)]
+pub struct AocClient {
+    base_url: String,
+    user_agent: Option<String>,
+    session: Option<String>,
+    profile: Option<String>,
+}
+
+impl AocClient {
+    /// A client that reads the session from `AOC_SESSION` or the session files of the profile
+    /// in `AOC_PROFILE`, unless it's given one with [`AocClient::with_session`] or another profile
+    /// with [`AocClient::with_profile`].
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            user_agent: None,
+            session: None,
+            profile: profile(),
+        }
+    }
+
+    /// Reads the session of `profile` instead of the one in `AOC_PROFILE`, `None` is the default
+    /// profile.
+    pub fn with_profile(mut self, profile: Option<&str>) -> Self {
+        self.profile = named_profile(profile);
+        self
+    }
+
+    /// Logs in with `session` instead of looking for one in the environment.
+    pub fn with_session(mut self, session: &str) -> Self {
+        self.session = Some(session.to_string());
+        self
+    }
+
+    /// Sends `user_agent` with every request, the website asks automated tools to say who runs them.
+    pub fn with_user_agent(mut self, user_agent: Option<String>) -> Self {
+        self.user_agent = user_agent;
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// The profile whose session is used, `None` for the default one.
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    /// Sends an authenticated request and returns the body. A missing page of a `puzzle` means it
+    /// isn't unlocked yet.
+    fn send(
+        &self,
+        puzzle: Option<(u32, u32)>,
+        url: String,
+        request: impl FnOnce(&Client, &str) -> RequestBuilder,
+    ) -> Result<String, AocError> {
+        let mut headers = HeaderMap::new();
+        let session = match &self.session {
+            Some(session) => session.clone(),
+            None => load_session(self.profile())?,
+        };
+        let cookie = HeaderValue::from_str(&format!("session={}", session.trim()))
+            .map_err(|_| AocError::InvalidSession)?;
+        headers.insert(COOKIE, cookie);
+        if let Some(user_agent) = &self.user_agent {
+            let value = HeaderValue::from_str(user_agent)
+                .map_err(|_| AocError::InvalidUserAgent(user_agent.clone()))?;
+            headers.insert(USER_AGENT, value);
+        }
+        let response = Client::builder()
+            .default_headers(headers)
+            .redirect(Policy::none())
+            .build()
+            .and_then(|client| request(&client, &url).send())
+            .map_err(|err| AocError::NetworkUnavailable(err.to_string()))?;
+        // redirects aren't followed, they only ever lead to the login page
+        match response.status().as_u16() {
+            200 => {}
+            404 => match puzzle {
+                Some((year, day)) => return Err(AocError::NotUnlocked { year, day }),
+                None => return Err(AocError::UnexpectedStatus { status: 404, url }),
+            },
+            300..=399 | 400 | 401 | 403 => return Err(AocError::InvalidSession),
+            429 => return Err(AocError::RateLimited),
+            status => return Err(AocError::UnexpectedStatus { status, url }),
         }
-        println!("file-missing, downloading input..");
-        download_input(year, day, &path);
+        response
+            .text()
+            .map_err(|err| AocError::NetworkUnavailable(err.to_string()))
+    }
+
+    pub fn download_input(&self, year: u32, day: u32) -> Result<String, AocError> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let input = self.send(Some((year, day)), url, |client, url| client.get(url))?;
+        if looks_like_html(&input) {
+            return Err(AocError::InvalidSession);
+        }
+        Ok(input)
+    }
+
+    /// The puzzle description, part two is only included once part one is solved.
+    pub fn download_puzzle(&self, year: u32, day: u32) -> Result<String, AocError> {
+        let url = format!("{}/{year}/day/{day}", self.base_url);
+        self.send(Some((year, day)), url, |client, url| client.get(url))
     }
 
-    let mut file = std::fs::File::open(path).unwrap();
-    file.read_to_string(&mut input).unwrap();
-    input
+    /// The name of the account the session belongs to, every page shows it when logged in.
+    pub fn whoami(&self) -> Result<String, AocError> {
+        let url = format!("{}/settings", self.base_url);
+        let page = self.send(None, url, |client, url| client.get(url))?;
+        let user = Regex::new(r#"<div class="user">([^<]*)"#).unwrap();
+        match user.captures(&page) {
+            Some(caps) if !caps[1].trim().is_empty() => Ok(caps[1].trim().to_string()),
+            _ => Err(AocError::InvalidSession),
+        }
+    }
+
+    pub fn submit_answer(
+        &self,
+        year: u32,
+        day: u32,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmitOutcome, AocError> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let page = self.send(Some((year, day)), url, |client, url| {
+            client
+                .post(url)
+                .form(&[("level", part.to_string()), ("answer", answer.to_string())])
+        })?;
+        SubmitOutcome::parse(&page)
+    }
+}
+
+/// The hint given with a wrong answer.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+/// What the website made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    Incorrect(Option<Hint>),
+    /// The part was already solved, the answer wasn't checked.
+    AlreadySolved,
+    /// An answer was submitted too recently, nothing was checked.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+}
+
+impl SubmitOutcome {
+    /// Parses the page returned after posting an answer.
+    pub fn parse(page: &str) -> Result<Self, AocError> {
+        // the message is the first paragraph of the page's only article
+        let article = Regex::new(r"(?s)<article>(.*?)</article>")
+            .unwrap()
+            .captures(page)
+            .map_or(page, |c| c.get(1).unwrap().as_str());
+        let text = Regex::new(r"<[^>]*>")
+            .unwrap()
+            .replace_all(article, "")
+            .split_whitespace()
+            .join(" ");
+        if text.contains("That's the right answer") {
+            Ok(SubmitOutcome::Correct)
+        } else if text.contains("That's not the right answer") {
+            let hint = match () {
+                _ if text.contains("your answer is too high") => Some(Hint::TooHigh),
+                _ if text.contains("your answer is too low") => Some(Hint::TooLow),
+                _ => None,
+            };
+            Ok(SubmitOutcome::Incorrect(hint))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Ok(SubmitOutcome::AlreadySolved)
+        } else if text.contains("You gave an answer too recently") {
+            let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait")
+                .unwrap()
+                .captures(&text)
+                .map(|c| {
+                    let minutes = c.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+                    let seconds: u64 = c[2].parse().unwrap();
+                    Duration::from_secs(minutes * 60 + seconds)
+                });
+            Ok(SubmitOutcome::RateLimited { wait })
+        } else if text.contains("please identify yourself") {
+            Err(AocError::InvalidSession)
+        } else {
+            Err(AocError::UnrecognizedResponse(
+                text.chars().take(200).collect(),
+            ))
+        }
+    }
+}
+
+impl fmt::Display for SubmitOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "correct"),
+            SubmitOutcome::Incorrect(Some(hint)) => write!(f, "incorrect, {hint}"),
+            SubmitOutcome::Incorrect(None) => write!(f, "incorrect"),
+            SubmitOutcome::AlreadySolved => write!(f, "already solved"),
+            SubmitOutcome::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate limited, wait {}s", wait.as_secs())
+            }
+            SubmitOutcome::RateLimited { wait: None } => write!(f, "rate limited"),
+        }
+    }
+}
+
+/// Puzzle inputs are never HTML, if we got some it's the login page.
+fn looks_like_html(content: &str) -> bool {
+    let start = content.trim_start().to_ascii_lowercase();
+    start.starts_with("<!doctype html") || start.starts_with("<html")
+}
+
+/// Writes to a temporary file first so a failed write never leaves a partial file behind.
+fn write_atomic(path: &Path, content: &str) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(format!(".{}.tmp", std::process::id()));
+    std::fs::write(&tmp, content)?;
+    std::fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = std::fs::remove_file(&tmp);
+    })
+}
+
+/// Cache of puzzle inputs, missing inputs are downloaded unless it's offline.
+#[derive(Debug, Clone)]
+pub struct InputManager {
+    dir: PathBuf,
+    offline: bool,
+    client: AocClient,
+}
+
+impl InputManager {
+    pub fn new(dir: impl Into<PathBuf>, offline: bool, client: AocClient) -> Self {
+        Self {
+            dir: dir.into(),
+            offline,
+            client,
+        }
+    }
+
+    /// Uses the directory in `AOC_INPUT_DIR` or `input/` in the project root, or the profile's
+    /// subdirectory of it.
+    pub fn from_env(offline: bool, client: AocClient) -> Self {
+        let dir = std::env::var_os(INPUT_DIR_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| Path::new(PROJECT_ROOT).join("input"));
+        Self::new(profile_dir(dir, profile().as_deref()), offline, client)
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    pub fn client(&self) -> &AocClient {
+        &self.client
+    }
+
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        self.dir.join(format!("{year}-{day}.in"))
+    }
+
+    fn is_cached(&self, year: u32, day: u32) -> bool {
+        // a login page that ended up in the cache counts as missing
+        std::fs::read_to_string(self.path(year, day))
+            .is_ok_and(|content| !looks_like_html(&content))
+    }
+
+    pub fn missing(&self, year: u32, days: &[u32]) -> Vec<u32> {
+        days.iter()
+            .copied()
+            .filter(|&day| !self.is_cached(year, day))
+            .collect()
+    }
+
+    /// Makes sure the inputs of all `days` are cached, returns the days that were downloaded.
+    pub fn prefetch(&self, year: u32, days: &[u32]) -> Result<Vec<u32>, AocError> {
+        let missing = self.missing(year, days);
+        if missing.is_empty() {
+            return Ok(missing);
+        }
+        if self.offline {
+            return Err(AocError::MissingInputs {
+                year,
+                days: missing,
+                dir: self.dir.clone(),
+            });
+        }
+        for &day in missing.iter() {
+            eprintln!("{year} day {day} missing, downloading input..");
+            let input = self.client.download_input(year, day)?;
+            write_atomic(&self.path(year, day), &input)?;
+        }
+        Ok(missing)
+    }
+
+    /// Caches the input of a puzzle that just unlocked. The website's clock can be a bit behind,
+    /// a puzzle that isn't unlocked yet is retried every second of `clock` for a few seconds.
+    pub fn fetch_unlocked(&self, clock: &dyn Clock, year: u32, day: u32) -> Result<(), AocError> {
+        for _ in 1..UNLOCK_RETRIES {
+            match self.prefetch(year, &[day]) {
+                Err(AocError::NotUnlocked { .. }) => clock.sleep(Duration::from_secs(1)),
+                result => return result.map(drop),
+            }
+        }
+        self.prefetch(year, &[day]).map(drop)
+    }
+
+    pub fn get(&self, year: u32, day: u32) -> Result<String, AocError> {
+        self.prefetch(year, &[day])?;
+        Ok(std::fs::read_to_string(self.path(year, day))?)
+    }
 }
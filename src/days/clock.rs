@@ -0,0 +1,183 @@
+use std::cell::Cell;
+use std::fmt;
+use std::io::Write;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Puzzles unlock at midnight US Eastern, which Advent of Code keeps at UTC-5 all of December.
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+pub trait Clock {
+    fn now(&self) -> SystemTime;
+    fn sleep(&self, duration: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration)
+    }
+}
+
+/// A clock that only moves when it's slept on.
+pub struct FakeClock {
+    now: Cell<SystemTime>,
+}
+
+impl FakeClock {
+    pub fn new(now: SystemTime) -> Self {
+        Self {
+            now: Cell::new(now),
+        }
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> SystemTime {
+        self.now.get()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration)
+    }
+}
+
+/// Days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: u32, month: u32, day: u32) -> u64 {
+    let (year, month, day) = (year as i64, month as i64, day as i64);
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    (era * 146097 + day_of_era - 719468) as u64
+}
+
+/// The date of a day since 1970-01-01, the inverse of [`days_from_civil`].
+fn civil_from_days(days: u64) -> (u32, u32, u32) {
+    let days = days as i64 + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year as u32, month as u32, day as u32)
+}
+
+fn unix_seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// When a puzzle unlocks.
+pub fn unlock_time(year: u32, day: u32) -> SystemTime {
+    let seconds = days_from_civil(year, 12, day) * 86400 + UNLOCK_HOUR_UTC * 3600;
+    UNIX_EPOCH + Duration::from_secs(seconds)
+}
+
+/// The puzzle of the current Advent of Code day, `None` outside of December 1 to 25.
+pub fn current_puzzle(now: SystemTime) -> Option<(u32, u32)> {
+    let eastern = unix_seconds(now).saturating_sub(UNLOCK_HOUR_UTC * 3600);
+    match civil_from_days(eastern / 86400) {
+        (year, 12, day @ 1..=25) => Some((year, day)),
+        _ => None,
+    }
+}
+
+/// The first puzzle that isn't unlocked yet.
+pub fn next_puzzle(now: SystemTime) -> (u32, u32) {
+    let eastern = unix_seconds(now).saturating_sub(UNLOCK_HOUR_UTC * 3600);
+    match civil_from_days(eastern / 86400) {
+        (year, 12, day @ 1..=24) => (year, day + 1),
+        (year, 12, _) => (year + 1, 1),
+        (year, _, _) => (year, 1),
+    }
+}
+
+/// A countdown like `2d 03:04:05`, days are left out when there are none.
+pub struct Remaining(pub Duration);
+
+impl fmt::Display for Remaining {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let seconds = self.0.as_secs();
+        let (days, hours) = (seconds / 86400, seconds / 3600 % 24);
+        let (minutes, seconds) = (seconds / 60 % 60, seconds % 60);
+        if days > 0 {
+            write!(f, "{days}d ")?;
+        }
+        write!(f, "{hours:02}:{minutes:02}:{seconds:02}")
+    }
+}
+
+/// Counts down to the unlock of a puzzle on `out`, every second in the last minute and every
+/// minute before that.
+pub fn wait_for_unlock(
+    clock: &dyn Clock,
+    year: u32,
+    day: u32,
+    out: &mut dyn Write,
+) -> std::io::Result<()> {
+    let unlock = unlock_time(year, day);
+    while let Ok(remaining) = unlock.duration_since(clock.now()) {
+        if remaining.is_zero() {
+            break;
+        }
+        write!(
+            out,
+            "\r{year} day {day} unlocks in {}",
+            Remaining(remaining)
+        )?;
+        out.flush()?;
+        let tick = match remaining <= Duration::from_secs(60) {
+            true => Duration::from_secs(1),
+            false => Duration::from_secs(60),
+        };
+        let step = match remaining.as_nanos() % tick.as_nanos() {
+            0 => tick,
+            rest => Duration::from_nanos(rest as u64),
+        };
+        clock.sleep(step);
+    }
+    writeln!(out, "\r{year} day {day} is unlocked{:12}", "")
+}
+
+/// The puzzle to solve today: `puzzle` when one is given, otherwise the one of the current day
+/// or with `wait` the next one to unlock. A puzzle that isn't unlocked yet is an error, unless
+/// `wait` counts down to its unlock on `out`.
+pub fn todays_puzzle(
+    clock: &dyn Clock,
+    puzzle: Option<(u32, u32)>,
+    wait: bool,
+    out: &mut dyn Write,
+) -> anyhow::Result<(u32, u32)> {
+    let now = clock.now();
+    let (year, day) = match (puzzle, wait) {
+        (Some(puzzle), _) => puzzle,
+        (None, true) => next_puzzle(now),
+        (None, false) => current_puzzle(now).unwrap_or_else(|| next_puzzle(now)),
+    };
+    match unlock_time(year, day).duration_since(now) {
+        Ok(remaining) if !remaining.is_zero() && !wait => {
+            let today = match puzzle {
+                Some(_) => "",
+                None => "no puzzle today, ",
+            };
+            anyhow::bail!(
+                "{today}{year} day {day} unlocks in {}, --wait waits for it",
+                Remaining(remaining)
+            );
+        }
+        Ok(remaining) if !remaining.is_zero() => wait_for_unlock(clock, year, day, out)?,
+        _ => {}
+    }
+    Ok((year, day))
+}
@@ -0,0 +1,198 @@
+use super::aoc_util::{AocError, PROJECT_ROOT};
+use super::params::Overrides;
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Overrides the example directory, defaults to `examples/` in the project root.
+pub const EXAMPLE_DIR_VAR: &str = "AOC_EXAMPLE_DIR";
+
+pub fn example_dir() -> PathBuf {
+    std::env::var_os(EXAMPLE_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(PROJECT_ROOT).join("examples"))
+}
+
+/// Expected answers of one example, parts the example doesn't cover are left out.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+pub struct ExampleAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+    /// Parameters the example needs other values of than the real input
+    #[serde(default, skip_serializing_if = "Overrides::is_empty")]
+    pub params: Overrides,
+}
+
+impl ExampleAnswers {
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            _ => self.part2.as_deref(),
+        }
+    }
+}
+
+/// The answers of an example file in `answers.toml`, a table or, when the file is checked with
+/// several parameter sets, an array of tables.
+///
+/// ```toml
+/// [["11.ex"]]
+/// part1 = "374"
+///
+/// [["11.ex"]]
+/// part2 = "1030"
+/// params = { expansion2 = 10 }
+/// ```
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(untagged)]
+enum AnswerSets {
+    One(ExampleAnswers),
+    Several(Vec<ExampleAnswers>),
+}
+
+impl From<AnswerSets> for Vec<ExampleAnswers> {
+    fn from(sets: AnswerSets) -> Self {
+        match sets {
+            AnswerSets::One(answers) => vec![answers],
+            AnswerSets::Several(sets) => sets,
+        }
+    }
+}
+
+impl From<Vec<ExampleAnswers>> for AnswerSets {
+    fn from(mut sets: Vec<ExampleAnswers>) -> Self {
+        match sets.len() {
+            1 => AnswerSets::One(sets.remove(0)),
+            _ => AnswerSets::Several(sets),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Example {
+    /// File name, e.g. `8-2.ex` for the second example of day 8. Files with several answer sets
+    /// have the parameters too, e.g. `11.ex expansion2=10`.
+    pub name: String,
+    pub input: String,
+    pub answers: ExampleAnswers,
+}
+
+/// Example inputs of a year in `{example_dir}/{year}/`, with their expected answers in `answers.toml`.
+///
+/// A day's examples are `{day}.ex` and any number of `{day}-{suffix}.ex` files.
+#[derive(Debug)]
+pub struct ExampleStore {
+    dir: PathBuf,
+    answers: BTreeMap<String, Vec<ExampleAnswers>>,
+}
+
+impl ExampleStore {
+    pub fn load(year: u32) -> anyhow::Result<Self> {
+        Self::open(example_dir().join(year.to_string()))
+    }
+
+    /// The store of a single year's directory.
+    pub fn open(dir: PathBuf) -> anyhow::Result<Self> {
+        let path = dir.join("answers.toml");
+        let answers: BTreeMap<String, AnswerSets> = match std::fs::read_to_string(&path) {
+            Ok(content) => toml::from_str(&content)
+                .with_context(|| format!("failed to parse {}", path.display()))?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display()))?,
+        };
+        let answers = answers
+            .into_iter()
+            .map(|(name, sets)| (name, sets.into()))
+            .collect();
+        Ok(Self { dir, answers })
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let path = self.dir.join("answers.toml");
+        let answers = self
+            .answers
+            .iter()
+            .map(|(name, sets)| (name, AnswerSets::from(sets.clone())))
+            .collect::<BTreeMap<_, _>>();
+        std::fs::write(&path, toml::to_string(&answers)?)
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The main example of a day, `{day}.ex`.
+    pub fn input(&self, day: u32) -> Result<String, AocError> {
+        let path = self.dir.join(format!("{day}.ex"));
+        if !path.exists() {
+            return Err(AocError::MissingExample(path));
+        }
+        Ok(std::fs::read_to_string(path)?)
+    }
+
+    /// Names of all example files of a day, the main example first.
+    pub fn names(&self, day: u32) -> anyhow::Result<Vec<String>> {
+        let entries = match std::fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => {
+                Err(err).with_context(|| format!("failed to read {}", self.dir.display()))?
+            }
+        };
+        let mut names = Vec::new();
+        for entry in entries {
+            let name = entry?.file_name().to_string_lossy().into_owned();
+            let Some(stem) = name.strip_suffix(".ex") else {
+                continue;
+            };
+            let prefix = stem.split_once('-').map_or(stem, |(prefix, _)| prefix);
+            if prefix.parse() == Ok(day) {
+                names.push(name);
+            }
+        }
+        names.sort_by_key(|name| (name.len(), name.clone()));
+        Ok(names)
+    }
+
+    /// All examples of a day with their expected answers, once per answer set.
+    pub fn examples(&self, day: u32) -> anyhow::Result<Vec<Example>> {
+        let mut examples = Vec::new();
+        for name in self.names(day)? {
+            let path = self.dir.join(&name);
+            let input = std::fs::read_to_string(&path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            let sets = match self.answers.get(&name) {
+                Some(sets) => sets.clone(),
+                None => vec![ExampleAnswers::default()],
+            };
+            let several = sets.len() > 1;
+            for answers in sets {
+                let name = match several && !answers.params.is_empty() {
+                    true => format!("{name} {}", answers.params),
+                    false => name.clone(),
+                };
+                examples.push(Example {
+                    name,
+                    input: input.clone(),
+                    answers,
+                });
+            }
+        }
+        Ok(examples)
+    }
+
+    /// Writes an example file and replaces its expected answers, `save` writes the answers.
+    pub fn add(&mut self, name: &str, input: &str, answers: ExampleAnswers) -> anyhow::Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let path = self.dir.join(name);
+        std::fs::write(&path, input)
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        self.answers.insert(name.to_string(), vec![answers]);
+        Ok(())
+    }
+}
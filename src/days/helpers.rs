@@ -0,0 +1,41 @@
+//! Parsing and geometry shared by the solutions.
+
+/// A grid of characters, one row per line. Surrounding whitespace is ignored.
+pub fn parse_grid(input: &str) -> Vec<Vec<char>> {
+    input
+        .trim()
+        .lines()
+        .map(|line| line.trim().chars().collect())
+        .collect()
+}
+
+/// Rows become columns, the grid must be rectangular.
+pub fn transpose<T: Copy>(grid: &[Vec<T>]) -> Vec<Vec<T>> {
+    match grid.first() {
+        Some(row) => (0..row.len())
+            .map(|j| grid.iter().map(|row| row[j]).collect())
+            .collect(),
+        None => vec![],
+    }
+}
+
+pub fn manhattan_distance(p1: (usize, usize), p2: (usize, usize)) -> usize {
+    p1.0.abs_diff(p2.0) + p1.1.abs_diff(p2.1)
+}
+
+/// The number of grid cells inside and on a closed polygon with axis aligned edges, like a lagoon
+/// that's dug out along a path. `polygon` ends where it starts, `perimeter` is its length.
+pub fn polygon_area(polygon: &[(isize, isize)], perimeter: isize) -> isize {
+    polygon[..polygon.len() - 1]
+        .iter()
+        .zip(polygon[1..].iter())
+        .fold(0, |mut acc, (p1, p2)| {
+            acc += (p1.0 + p2.0) * (p1.1 - p2.1); // trapezoid/shoelace formula
+            acc
+        })
+        .abs()
+        / 2
+        // pick's theorem
+        + perimeter / 2
+        + 1
+}
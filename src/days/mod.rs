@@ -1,84 +1,255 @@
+pub mod answers;
 pub mod aoc_util;
-pub mod day1;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day2;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
-
-fn day_modules() -> Vec<fn(&str) -> (usize, usize)> {
-    vec![
-        day1::solve,
-        day2::solve,
-        day3::solve,
-        day4::solve,
-        day5::solve,
-        day6::solve,
-        day7::solve,
-        day8::solve,
-        day9::solve,
-        day10::solve,
-        day11::solve,
-        day12::solve,
-        day13::solve,
-        day14::solve,
-        day15::solve,
-        day16::solve,
-        day17::solve,
-        day18::solve,
-        day19::solve,
-        day20::solve,
-        day21::solve,
-        day22::solve,
-        day23::solve,
-        day24::solve,
-        day25::solve,
-    ]
+pub mod clock;
+pub mod examples;
+pub mod helpers;
+pub mod panics;
+pub mod params;
+pub mod puzzle_page;
+pub mod solution;
+pub mod submissions;
+pub mod workers;
+
+pub use panics::SolverPanic;
+pub use params::Overrides;
+pub use solution::{Answer, Solution, Solved, Solver, Step, Timings};
+pub use workers::Workers;
+
+use crate::years::SOLVERS;
+use anyhow::Context;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+    #[default]
+    Both,
+}
+
+impl Part {
+    /// The part numbers this selects.
+    pub fn numbers(self) -> Vec<u8> {
+        match self {
+            Part::One => vec![1],
+            Part::Two => vec![2],
+            Part::Both => vec![1, 2],
+        }
+    }
+}
+
+pub fn solver(year: u32, day: u32) -> Option<&'static dyn Solver> {
+    SOLVERS
+        .iter()
+        .find(|(y, d, _)| (*y, *d) == (year, day))
+        .map(|(_, _, solver)| *solver)
+}
+
+/// The years that have at least one solution.
+pub fn solved_years() -> Vec<u32> {
+    let mut years = SOLVERS.iter().map(|(year, _, _)| *year).collect::<Vec<_>>();
+    years.dedup();
+    years
+}
+
+/// The days of `year` that have a solution.
+pub fn solved_days(year: u32) -> Vec<u32> {
+    SOLVERS
+        .iter()
+        .filter(|(y, _, _)| *y == year)
+        .map(|(_, day, _)| *day)
+        .collect()
 }
 
-#[allow(dead_code)]
-pub fn solve_all(year: u32) {
-    day_modules().iter().enumerate().for_each(|(i, solve)| {
-        let day = (i + 1) as u32;
-        let input = aoc_util::get_input(year, day, false);
-        let solution = solve(input.trim());
-        println!("Day{day}: {solution:?}")
-    })
+/// The last day of `year` that has a solution.
+pub fn latest(year: u32) -> Option<u32> {
+    solved_days(year).last().copied()
 }
 
-#[allow(dead_code)]
-pub fn solve_day(year: u32, day: usize) {
-    let input = aoc_util::get_input(year, day as u32, false);
-    let solution = day_modules()[day - 1](input.trim());
-    println!("Day{day}: {solution:?}")
+/// The solver of a day, if `params` are all parameters of the day.
+fn checked_solver(year: u32, day: u32, params: &Overrides) -> anyhow::Result<&'static dyn Solver> {
+    let Some(solver) = solver(year, day) else {
+        anyhow::bail!("no solution for {year} day {day}");
+    };
+    solver
+        .check_params(params)
+        .with_context(|| format!("{year} day {day}"))?;
+    Ok(solver)
 }
 
-#[allow(dead_code)]
-pub fn solve_latest(year: u32) {
-    let latest = day_modules().len();
-    solve_day(year, latest)
+/// Solving a day in attempts. A part that panics or times out ends an attempt, the parts after
+/// it are solved in the next attempt, which parses the input again.
+struct Attempts {
+    solved: Solved,
+    pending: Vec<u8>,
+    parsed: bool,
+    /// Whether the current attempt got past parsing
+    attempt_parsed: bool,
 }
 
-// pub fn solve(input: &str) -> (usize, usize) {
-//     println!("{input}");
-//     (0,0)
-// }
+impl Attempts {
+    fn new(part: Part) -> Self {
+        Self {
+            solved: Solved::default(),
+            pending: part.numbers(),
+            parsed: false,
+            attempt_parsed: false,
+        }
+    }
+
+    /// The parts to solve in the next attempt.
+    fn next(&mut self) -> Option<Part> {
+        self.attempt_parsed = false;
+        match self.pending[..] {
+            [] => None,
+            [_, _] => Some(Part::Both),
+            [1] => Some(Part::One),
+            _ => Some(Part::Two),
+        }
+    }
+
+    fn step(&mut self, step: Step) {
+        match step {
+            // keep the time of the first parse
+            Step::Parsed(_) if self.parsed => {}
+            Step::Parsed(_) => {
+                self.solved.add(step);
+                self.parsed = true;
+            }
+            Step::Part(done, ..) => {
+                self.solved.add(step);
+                self.pending.retain(|&p| p != done);
+            }
+        }
+        self.attempt_parsed = true;
+    }
+
+    /// The part being solved panicked, or all of them when parsing did.
+    fn panicked(&mut self, panic: SolverPanic) {
+        let failed = match self.attempt_parsed {
+            true => vec![self.pending.remove(0)],
+            false => std::mem::take(&mut self.pending),
+        };
+        for part in failed {
+            self.solved.failed.push((part, panic.clone()));
+        }
+    }
+
+    fn timed_out(&mut self, timeout: Duration) {
+        let current = self.pending.remove(0);
+        let elapsed = match current {
+            1 => &mut self.solved.timings.part1,
+            _ => &mut self.solved.timings.part2,
+        };
+        *elapsed = timeout;
+        self.solved.timed_out.push(current);
+    }
+}
+
+/// Solves a day with `params` instead of some of its defaults. A part whose solution panics is
+/// marked as failed, the other parts are still solved.
+pub fn solve_day(
+    year: u32,
+    day: u32,
+    input: &str,
+    part: Part,
+    params: &Overrides,
+) -> anyhow::Result<Solved> {
+    let solver = checked_solver(year, day, params)?;
+    let mut attempts = Attempts::new(part);
+    while let Some(part) = attempts.next() {
+        let solved = panics::catch(|| {
+            solver.solve_steps(input, part, params, &mut |step| attempts.step(step))
+        });
+        if let Err(panic) = solved {
+            attempts.panicked(panic);
+        }
+    }
+    Ok(attempts.solved)
+}
+
+/// What a solve on a thread of [`Workers`] reports back.
+enum Progress {
+    /// The solve got a thread, the deadline starts now
+    Started,
+    Step(Step),
+    Panicked(SolverPanic),
+}
+
+/// Like [`solve_day`], but gives up on a part once it runs longer than `timeout` and marks it as
+/// timed out. Parsing counts towards the first part, waiting for a thread doesn't count.
+///
+/// Solves on a thread of `workers`. A part that timed out can't be stopped, it keeps its thread
+/// until the process exits.
+pub fn solve_day_timeout(
+    year: u32,
+    day: u32,
+    input: &str,
+    part: Part,
+    params: &Overrides,
+    timeout: Option<Duration>,
+    workers: &Workers,
+) -> anyhow::Result<Solved> {
+    let Some(timeout) = timeout else {
+        let worker = workers.reserve();
+        return worker
+            .pool()
+            .install(|| solve_day(year, day, input, part, params));
+    };
+    let solver = checked_solver(year, day, params)?;
+    let mut attempts = Attempts::new(part);
+    while let Some(part) = attempts.next() {
+        let (send, steps) = mpsc::channel();
+        let (input, params) = (input.to_string(), params.clone());
+        let worker = workers.reserve();
+        worker.pool().spawn(move || {
+            let _ = send.send(Progress::Started);
+            let solved = panics::catch(|| {
+                solver.solve_steps(&input, part, &params, &mut |step| {
+                    let _ = send.send(Progress::Step(step));
+                })
+            });
+            if let Err(panic) = solved {
+                let _ = send.send(Progress::Panicked(panic));
+            }
+        });
+        // the job can sit in the pool's queue while its threads help out with the parallel work
+        // of other days
+        let mut deadline: Option<Instant> = None;
+        loop {
+            let progress = match deadline {
+                None => steps.recv().map_err(|_| RecvTimeoutError::Disconnected),
+                Some(deadline) => {
+                    steps.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                }
+            };
+            match progress {
+                Ok(Progress::Started) => deadline = Some(Instant::now() + timeout),
+                Ok(Progress::Step(step)) => {
+                    if let Step::Part(..) = step {
+                        deadline = Some(Instant::now() + timeout);
+                    }
+                    attempts.step(step);
+                }
+                Ok(Progress::Panicked(panic)) => {
+                    attempts.panicked(panic);
+                    break;
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    attempts.timed_out(timeout);
+                    worker.stuck();
+                    break;
+                }
+                Err(RecvTimeoutError::Disconnected) if attempts.pending.is_empty() => break,
+                Err(RecvTimeoutError::Disconnected) => {
+                    anyhow::bail!(
+                        "{year} day {day} part {} stopped without an answer",
+                        attempts.pending[0]
+                    )
+                }
+            }
+        }
+    }
+    Ok(attempts.solved)
+}
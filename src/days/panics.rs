@@ -0,0 +1,74 @@
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+/// A panic caught while solving a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolverPanic {
+    pub message: String,
+    /// `file:line:column`, unknown when the panic happened on another thread
+    pub location: Option<String>,
+}
+
+impl fmt::Display for SolverPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {location}: {}", self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for SolverPanic {}
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LAST: RefCell<Option<SolverPanic>> = const { RefCell::new(None) };
+}
+
+fn message(payload: &(dyn std::any::Any + Send)) -> String {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "Box<dyn Any>".to_string(),
+    }
+}
+
+/// Keeps the message and location of panics inside [`catch`] instead of printing them, other
+/// panics go to the default hook.
+fn install_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING.get() {
+                return default(info);
+            }
+            let caught = SolverPanic {
+                message: message(info.payload()),
+                location: info.location().map(|l| l.to_string()),
+            };
+            LAST.set(Some(caught));
+        }));
+    });
+}
+
+/// Runs `f`, turning a panic into an error.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, SolverPanic> {
+    install_hook();
+    let catching = CATCHING.replace(true);
+    LAST.set(None);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(catching);
+    // panics on rayon threads only get here through the payload, without a location
+    result.map_err(|payload| {
+        LAST.take().unwrap_or_else(|| SolverPanic {
+            message: message(&*payload),
+            location: None,
+        })
+    })
+}
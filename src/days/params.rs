@@ -0,0 +1,192 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Constants of a day that differ between the examples and the real input, like the number of
+/// steps to take. The defaults are for the real input.
+pub trait Params: Default {
+    /// Names and values of the parameters, in declaration order.
+    fn values(&self) -> Vec<(&'static str, String)>;
+
+    /// Parses `value` into the parameter `name`.
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError>;
+
+    /// The defaults with `overrides` applied.
+    fn with(overrides: &Overrides) -> Result<Self, ParamError> {
+        let mut params = Self::default();
+        for (name, value) in overrides.iter() {
+            params.set(name, value)?;
+        }
+        Ok(params)
+    }
+}
+
+/// Days without parameters.
+impl Params for () {
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec![]
+    }
+
+    fn set(&mut self, name: &str, _: &str) -> Result<(), ParamError> {
+        Err(ParamError::Unknown(name.to_string(), vec![]))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    /// The name and the parameters that do exist, with their defaults
+    Unknown(String, Vec<(&'static str, String)>),
+    Invalid {
+        name: &'static str,
+        value: String,
+        error: String,
+    },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamError::Unknown(name, known) if known.is_empty() => {
+                write!(f, "no parameter `{name}`, the day has no parameters")
+            }
+            ParamError::Unknown(name, known) => {
+                let known = known
+                    .iter()
+                    .map(|(name, default)| format!("{name} (default {default})"))
+                    .collect::<Vec<_>>();
+                write!(f, "no parameter `{name}`, try {}", known.join(", "))
+            }
+            ParamError::Invalid { name, value, error } => {
+                write!(f, "invalid value `{value}` for parameter `{name}`: {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParamError {}
+
+/// Declares a day's parameters as a struct whose `Default` is the real input's values.
+///
+/// ```ignore
+/// params! {
+///     pub struct Day21Params {
+///         /// Steps of part 1
+///         steps1: usize = 64,
+///     }
+/// }
+/// ```
+macro_rules! params {
+    (
+        $vis:vis struct $name:ident {
+            $($(#[$doc:meta])* $field:ident: $ty:ty = $default:expr),* $(,)?
+        }
+    ) => {
+        #[derive(Debug, Clone)]
+        $vis struct $name {
+            $($(#[$doc])* pub $field: $ty),*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self {
+                    $($field: $default),*
+                }
+            }
+        }
+
+        impl $crate::days::params::Params for $name {
+            fn values(&self) -> Vec<(&'static str, String)> {
+                vec![$((stringify!($field), self.$field.to_string())),*]
+            }
+
+            fn set(
+                &mut self,
+                name: &str,
+                value: &str,
+            ) -> Result<(), $crate::days::params::ParamError> {
+                match name {
+                    $(stringify!($field) => {
+                        self.$field = value.trim().parse().map_err(|err| {
+                            $crate::days::params::ParamError::Invalid {
+                                name: stringify!($field),
+                                value: value.to_string(),
+                                error: format!("{err}"),
+                            }
+                        })?;
+                        Ok(())
+                    })*
+                    _ => Err($crate::days::params::ParamError::Unknown(
+                        name.to_string(),
+                        self.values(),
+                    )),
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use params;
+
+/// Parameter values by name, given as `NAME=VALUE` on the command line or as a table in an
+/// examples `answers.toml`.
+#[derive(Serialize, Default, Debug, Clone, PartialEq, Eq)]
+pub struct Overrides(BTreeMap<String, String>);
+
+impl Overrides {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    /// Parses a `NAME=VALUE` command line argument.
+    pub fn parse_arg(arg: &str) -> Result<(String, String), String> {
+        match arg.split_once('=') {
+            Some((name, value)) if !name.trim().is_empty() => {
+                Ok((name.trim().to_string(), value.to_string()))
+            }
+            _ => Err(format!("`{arg}` is not NAME=VALUE")),
+        }
+    }
+}
+
+/// `NAME=VALUE` pairs like on the command line.
+impl fmt::Display for Overrides {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pairs = self
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>();
+        write!(f, "{}", pairs.join(" "))
+    }
+}
+
+impl FromIterator<(String, String)> for Overrides {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// Numbers and booleans are fine too, `steps = 6` needs no quotes.
+impl<'de> Deserialize<'de> for Overrides {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = BTreeMap::<String, toml::Value>::deserialize(deserializer)?;
+        values
+            .into_iter()
+            .map(|(name, value)| match value {
+                toml::Value::String(s) => Ok((name, s)),
+                toml::Value::Integer(n) => Ok((name, n.to_string())),
+                toml::Value::Float(n) => Ok((name, n.to_string())),
+                toml::Value::Boolean(b) => Ok((name, b.to_string())),
+                other => Err(serde::de::Error::custom(format!(
+                    "parameter `{name}` must be a string, number or boolean, not {}",
+                    other.type_str()
+                ))),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,58 @@
+use super::examples::ExampleAnswers;
+use regex::Regex;
+
+fn unescape(html: &str) -> String {
+    let text = Regex::new(r"<[^>]*>").unwrap().replace_all(html, "");
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The example blocks and the last emphasized code of one part's description.
+fn scan_part(article: &str) -> (Vec<String>, Option<String>) {
+    // blocks with emphasis are worked solutions, not inputs
+    let blocks = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>")
+        .unwrap()
+        .captures_iter(article)
+        .filter(|c| !c[1].contains("<em>"))
+        .map(|c| unescape(&c[1]))
+        .collect();
+    // the answer to the example is the last thing the description emphasizes
+    let answer = Regex::new(r"(?s)<code><em>(.*?)</em></code>")
+        .unwrap()
+        .captures_iter(article)
+        .last()
+        .map(|c| unescape(&c[1]).trim().to_string());
+    (blocks, answer)
+}
+
+/// Extracts the examples and their answers from a puzzle description page, main example first.
+///
+/// Each part takes its first `<pre><code>` block without emphasis as its example, a part without
+/// one reuses the previous part's example. Pages for a part that isn't unlocked just have fewer descriptions.
+pub fn extract_examples(page: &str) -> Vec<(String, ExampleAnswers)> {
+    let mut examples: Vec<(String, ExampleAnswers)> = Vec::new();
+    let articles = Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+    for (i, article) in articles.captures_iter(page).take(2).enumerate() {
+        let (blocks, answer) = scan_part(&article[1]);
+        let index = match blocks.first() {
+            Some(block) => match examples.iter().position(|(input, _)| input == block) {
+                Some(index) => index,
+                None => {
+                    examples.push((block.clone(), ExampleAnswers::default()));
+                    examples.len() - 1
+                }
+            },
+            None if examples.is_empty() => continue,
+            None => examples.len() - 1,
+        };
+        let answers = &mut examples[index].1;
+        match i {
+            0 => answers.part1 = answer,
+            _ => answers.part2 = answer,
+        }
+    }
+    examples
+}
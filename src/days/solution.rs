@@ -0,0 +1,186 @@
+use super::panics::SolverPanic;
+use super::params::{Overrides, ParamError, Params};
+use super::Part;
+use num::BigInt;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    UInt(u128),
+    Big(BigInt),
+    Text(String),
+    /// The part has no answer, e.g. part 2 of day 25.
+    None,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::UInt(n) => write!(f, "{n}"),
+            Answer::Big(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::None => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident: $($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::$variant(value.into())
+            }
+        })*
+    };
+}
+
+answer_from!(Int: i8, i16, i32, i64, u8, u16, u32);
+answer_from!(UInt: u64, u128);
+answer_from!(Big: BigInt);
+answer_from!(Text: String, &str);
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Int(value as i64)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::UInt(value as u128)
+    }
+}
+
+/// A day's solution, the input is parsed once and shared by both parts.
+pub trait Solution {
+    type Parsed<'a>;
+    /// Constants the examples need different values of, `()` for most days.
+    type Params: Params;
+
+    fn parse(input: &str) -> Self::Parsed<'_>;
+    fn part1(parsed: &Self::Parsed<'_>, params: &Self::Params) -> Answer;
+    fn part2(parsed: &Self::Parsed<'_>, params: &Self::Params) -> Answer;
+}
+
+/// The answers of the requested parts, `None` for parts that weren't solved.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Solved {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    pub timings: Timings,
+    /// Parts that were given up on because they took too long, they have no answer
+    pub timed_out: Vec<u8>,
+    /// Parts whose solution panicked, they have no answer
+    pub failed: Vec<(u8, SolverPanic)>,
+}
+
+impl Solved {
+    /// The part numbers and answers of the parts that were solved and have an answer.
+    pub fn answers(&self) -> impl Iterator<Item = (u8, &Answer)> {
+        [(1, &self.part1), (2, &self.part2)]
+            .into_iter()
+            .filter_map(|(part, answer)| Some((part, answer.as_ref()?)))
+            .filter(|(_, answer)| **answer != Answer::None)
+    }
+
+    /// Why a part has no answer, if its solution panicked.
+    pub fn failure(&self, part: u8) -> Option<&SolverPanic> {
+        self.failed
+            .iter()
+            .find(|(failed, _)| *failed == part)
+            .map(|(_, panic)| panic)
+    }
+
+    pub fn add(&mut self, step: Step) {
+        match step {
+            Step::Parsed(elapsed) => self.timings.parse = elapsed,
+            Step::Part(1, answer, elapsed) => {
+                self.part1 = Some(answer);
+                self.timings.part1 = elapsed;
+            }
+            Step::Part(_, answer, elapsed) => {
+                self.part2 = Some(answer);
+                self.timings.part2 = elapsed;
+            }
+        }
+    }
+}
+
+/// A finished step of solving a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Step {
+    Parsed(Duration),
+    Part(u8, Answer, Duration),
+}
+/// Time spent parsing and in each part, parts that weren't solved take no time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Object safe counterpart of [`Solution`] so days can live in one registry.
+pub trait Solver: Sync {
+    /// Checks that every override is a parameter of the day with a valid value.
+    fn check_params(&self, overrides: &Overrides) -> Result<(), ParamError>;
+
+    /// Parses the input and solves the requested parts, reporting each step as it finishes.
+    ///
+    /// Panics on invalid `overrides`, see [`Solver::check_params`].
+    fn solve_steps(
+        &self,
+        input: &str,
+        part: Part,
+        overrides: &Overrides,
+        step: &mut dyn FnMut(Step),
+    );
+
+    fn solve(&self, input: &str, part: Part, overrides: &Overrides) -> Solved {
+        let mut solved = Solved::default();
+        self.solve_steps(input, part, overrides, &mut |step| solved.add(step));
+        solved
+    }
+}
+
+impl<S: Solution + Sync> Solver for S {
+    fn check_params(&self, overrides: &Overrides) -> Result<(), ParamError> {
+        S::Params::with(overrides).map(drop)
+    }
+
+    fn solve_steps(
+        &self,
+        input: &str,
+        part: Part,
+        overrides: &Overrides,
+        step: &mut dyn FnMut(Step),
+    ) {
+        let params = S::Params::with(overrides).unwrap_or_else(|err| panic!("{err}"));
+        let (parsed, parse) = timed(|| S::parse(input));
+        step(Step::Parsed(parse));
+        if matches!(part, Part::One | Part::Both) {
+            let (answer, elapsed) = timed(|| S::part1(&parsed, &params));
+            step(Step::Part(1, answer, elapsed));
+        }
+        if matches!(part, Part::Two | Part::Both) {
+            let (answer, elapsed) = timed(|| S::part2(&parsed, &params));
+            step(Step::Part(2, answer, elapsed));
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use super::answers::answer_dir;
+use super::aoc_util::Hint;
+use super::Answer;
+use anyhow::Context;
+use num::BigInt;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub answer: String,
+    pub correct: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Hint>,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug)]
+struct DaySubmissions {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part1: Vec<Submission>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part2: Vec<Submission>,
+}
+
+/// Why a candidate answer can't be right, judging by earlier submissions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KnownWrong {
+    Rejected(Option<Hint>),
+    /// At least as high as an answer that was too high.
+    AboveBound(String),
+    /// At most as low as an answer that was too low.
+    BelowBound(String),
+}
+
+impl fmt::Display for KnownWrong {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KnownWrong::Rejected(Some(hint)) => write!(f, "was already rejected as {hint}"),
+            KnownWrong::Rejected(None) => write!(f, "was already rejected"),
+            KnownWrong::AboveBound(bound) => write!(f, "is too high, {bound} already was"),
+            KnownWrong::BelowBound(bound) => write!(f, "is too low, {bound} already was"),
+        }
+    }
+}
+
+/// Every answer of a year the website has checked, kept in `{answer_dir}/{year}-submissions.toml`.
+#[derive(Debug)]
+pub struct SubmissionLog {
+    path: PathBuf,
+    days: BTreeMap<String, DaySubmissions>,
+}
+
+impl SubmissionLog {
+    /// The log of `year` in the answer directory.
+    pub fn load(year: u32) -> anyhow::Result<Self> {
+        Self::open(&answer_dir(), year)
+    }
+
+    /// The log of `year` in `dir`.
+    pub fn open(dir: &Path, year: u32) -> anyhow::Result<Self> {
+        let path = dir.join(format!("{year}-submissions.toml"));
+        let days = match std::fs::read_to_string(&path) {
+            Ok(content) => toml::from_str(&content)
+                .with_context(|| format!("failed to parse {}", path.display()))?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display()))?,
+        };
+        Ok(Self { path, days })
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&self.path, toml::to_string(&self.days)?)
+            .with_context(|| format!("failed to write {}", self.path.display()))
+    }
+
+    pub fn submissions(&self, day: u32, part: u8) -> &[Submission] {
+        let Some(submissions) = self.days.get(&format!("day{day}")) else {
+            return &[];
+        };
+        match part {
+            1 => &submissions.part1,
+            _ => &submissions.part2,
+        }
+    }
+
+    /// Checks `answer` against the rejected answers and the bounds their hints give.
+    pub fn check(&self, day: u32, part: u8, answer: &Answer) -> Option<KnownWrong> {
+        let answer = answer.to_string();
+        let rejected = self
+            .submissions(day, part)
+            .iter()
+            .filter(|s| !s.correct)
+            .collect::<Vec<_>>();
+        if let Some(earlier) = rejected.iter().find(|s| s.answer == answer) {
+            return Some(KnownWrong::Rejected(earlier.hint));
+        }
+        let value = answer.parse::<BigInt>().ok()?;
+        let bounds = |hint| {
+            rejected
+                .iter()
+                .filter(move |s| s.hint == Some(hint))
+                .filter_map(|s| Some((s.answer.parse::<BigInt>().ok()?, &s.answer)))
+        };
+        if let Some((_, bound)) = bounds(Hint::TooHigh).filter(|(b, _)| value >= *b).min() {
+            return Some(KnownWrong::AboveBound(bound.clone()));
+        }
+        if let Some((_, bound)) = bounds(Hint::TooLow).filter(|(b, _)| value <= *b).max() {
+            return Some(KnownWrong::BelowBound(bound.clone()));
+        }
+        None
+    }
+
+    pub fn record(&mut self, day: u32, part: u8, submission: Submission) {
+        let submissions = self.days.entry(format!("day{day}")).or_default();
+        match part {
+            1 => submissions.part1.push(submission),
+            _ => submissions.part2.push(submission),
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use rayon::{ThreadPool, ThreadPoolBuildError, ThreadPoolBuilder};
+use std::sync::{Arc, Condvar, Mutex};
+
+/// The threads to solve on, days that use rayon run their parallel work on the same threads.
+///
+/// Every solve reserves a thread first, so there are never more solves than threads. A reserved
+/// thread can still be busy with parallel work of other days for a while, solves only start once
+/// a thread picks them up. A part that timed out can't be stopped and keeps its thread, once
+/// every thread of the pool is stuck like that the next solves get a new pool.
+pub struct Workers {
+    jobs: usize,
+    state: Mutex<State>,
+    freed: Condvar,
+}
+
+struct State {
+    pool: Arc<ThreadPool>,
+    free: usize,
+    stuck: usize,
+}
+
+impl State {
+    fn new(jobs: usize) -> Result<Self, ThreadPoolBuildError> {
+        let pool = ThreadPoolBuilder::new().num_threads(jobs).build()?;
+        Ok(Self {
+            free: pool.current_num_threads(),
+            pool: Arc::new(pool),
+            stuck: 0,
+        })
+    }
+}
+
+impl Workers {
+    /// `jobs` threads, 0 uses every core.
+    pub fn new(jobs: usize) -> Result<Self, ThreadPoolBuildError> {
+        Ok(Self {
+            jobs,
+            state: Mutex::new(State::new(jobs)?),
+            freed: Condvar::new(),
+        })
+    }
+
+    /// Waits for a free thread.
+    pub fn reserve(&self) -> Worker<'_> {
+        let state = self.state.lock().unwrap();
+        let mut state = self.freed.wait_while(state, |s| s.free == 0).unwrap();
+        state.free -= 1;
+        Worker {
+            workers: self,
+            pool: state.pool.clone(),
+            stuck: false,
+        }
+    }
+}
+
+/// A thread of [`Workers`] reserved for one solve, it's free again when this is dropped.
+pub struct Worker<'a> {
+    workers: &'a Workers,
+    pool: Arc<ThreadPool>,
+    stuck: bool,
+}
+
+impl Worker<'_> {
+    pub fn pool(&self) -> &ThreadPool {
+        &self.pool
+    }
+
+    /// The solve gave up on a part that keeps running, the thread doesn't become free again.
+    pub fn stuck(mut self) {
+        self.stuck = true;
+    }
+}
+
+impl Drop for Worker<'_> {
+    fn drop(&mut self) {
+        let mut state = self.workers.state.lock().unwrap();
+        // a pool that was replaced is left to its stuck parts
+        if !Arc::ptr_eq(&state.pool, &self.pool) {
+            return;
+        }
+        match self.stuck {
+            false => state.free += 1,
+            true => {
+                state.stuck += 1;
+                if state.stuck < state.pool.current_num_threads() {
+                    return;
+                }
+                *state = State::new(self.workers.jobs).expect("failed to start solver threads");
+            }
+        }
+        self.workers.freed.notify_all();
+    }
+}
@@ -0,0 +1,29 @@
+//! Advent of Code solutions and the tools to run them, the `aoc2023` binary is the command line
+//! on top of this.
+//!
+//! Every day implements [`Solution`], [`solver`] looks one up by year and day and
+//! [`InputManager`] gets the inputs from a cache directory or the website. Helpers the solutions
+//! share are in [`helpers`].
+//!
+//! The stores and the client read their directories and the session from the `AOC_*` variables
+//! by default, [`InputManager::new`], [`AnswerStore::open`], [`ExampleStore::open`] and
+//! [`AocClient::with_session`] take them explicitly.
+
+mod days;
+pub mod years;
+
+pub use days::answers::{answer_dir, AnswerStore, Verdict, ANSWER_DIR_VAR};
+pub use days::aoc_util::{
+    config_dir, profile, profile_dir, session_files, AocClient, AocError, Hint, InputManager,
+    SubmitOutcome, CONFIG_DIR_VAR, DEFAULT_BASE_URL, INPUT_DIR_VAR, PROFILE_VAR, PROJECT_ROOT,
+    SESSION_VAR,
+};
+pub use days::examples::{example_dir, Example, ExampleAnswers, ExampleStore, EXAMPLE_DIR_VAR};
+pub use days::params::{ParamError, Params};
+pub use days::puzzle_page::extract_examples;
+pub use days::submissions::{KnownWrong, Submission, SubmissionLog};
+pub use days::{clock, helpers};
+pub use days::{
+    latest, solve_day, solve_day_timeout, solved_days, solved_years, solver, Answer, Overrides,
+    Part, Solution, Solved, Solver, SolverPanic, Step, Timings, Workers,
+};
@@ -1,7 +1,57 @@
-mod days;
+mod bench;
+mod cli;
+mod config;
+mod report;
+mod runner;
+mod scaffold;
+mod table;
+mod today;
+mod watch;
 
-fn main() {
-    days::solve_latest(2023)
-    //days::solve_day(2023, 24)
-    //days::solve_all(2023);
+use aoc2023::clock;
+use aoc2023::{profile_dir, AocClient, InputManager};
+use cli::Command;
+use config::Config;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> anyhow::Result<()> {
+    let config = Config::load()?;
+    let cli = config.parse();
+    let client = AocClient::new(&cli.base_url)
+        .with_profile(cli.profile.as_deref())
+        .with_user_agent(config.user_agent.clone());
+    let profile = client.profile().map(String::from);
+    let input_dir = match &cli.input_dir {
+        Some(dir) => dir.clone(),
+        None => profile_dir(config.input_dir(), profile.as_deref()),
+    };
+    let answer_dir = profile_dir(config.answer_dir(), profile.as_deref());
+    let inputs = InputManager::new(input_dir, cli.offline, client);
+    match &cli.command {
+        Some(Command::Run(args)) => runner::run(args, &inputs, &answer_dir),
+        Some(Command::Verify(args)) => runner::verify(args, &inputs, &answer_dir),
+        Some(Command::Examples(args)) => runner::examples(args, &mut std::io::stdout()),
+        Some(Command::NewDay(args)) => scaffold::new_day(args),
+        Some(Command::Extract(args)) => runner::extract(args, &inputs),
+        Some(Command::Bench(args)) => bench::bench(args, &inputs),
+        Some(Command::Fetch(args)) => runner::fetch(args, &inputs),
+        Some(Command::Summary) => runner::summary(&inputs, &answer_dir),
+        Some(Command::Submit(args)) => runner::submit(args, &inputs, &answer_dir),
+        Some(Command::Watch(args)) => watch::watch(args, &inputs),
+        Some(Command::Today(args)) => {
+            today::today(args, &cli.run, &inputs, &answer_dir, &clock::SystemClock)
+        }
+        Some(Command::Whoami) => runner::whoami(&inputs),
+        None => runner::run(&cli.run, &inputs, &answer_dir),
+    }
 }
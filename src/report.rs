@@ -0,0 +1,161 @@
+use aoc2023::{Answer, Solved, Verdict};
+use serde::{Deserialize, Serialize};
+
+/// Output format of `run`.
+#[derive(clap::ValueEnum, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// One line for a single day, a table for several
+    #[default]
+    Table,
+    /// An array of records, one per solved part
+    Json,
+    /// A header and one row per solved part, with the same columns as the JSON records
+    Csv,
+}
+
+/// Whether an answer matches the answer store. Example and `--input` runs are `unchecked`, parts
+/// that ran out of time are `timeout` and have an empty answer.
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Verification {
+    Pass,
+    Fail,
+    Unknown,
+    Unchecked,
+    Timeout,
+    /// The solution panicked, `error` has the panic message
+    Failed,
+}
+
+impl From<&Verdict> for Verification {
+    fn from(verdict: &Verdict) -> Self {
+        match verdict {
+            Verdict::Pass => Verification::Pass,
+            Verdict::Fail { .. } => Verification::Fail,
+            Verdict::Unknown => Verification::Unknown,
+        }
+    }
+}
+
+/// One solved part. The fields and their order are the schema of the JSON and CSV output, only
+/// ever add new fields at the end.
+#[derive(Serialize, Debug)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    /// Parsing time in microseconds, shared by both parts of a day
+    pub parse_us: u128,
+    /// Time spent in the part in microseconds, without parsing
+    pub part_us: u128,
+    pub verification: Verification,
+    /// The stored answer, only set when verification failed
+    pub expected: Option<String>,
+    /// Why the part failed, only set when the solution panicked
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub const COLUMNS: [&'static str; 9] = [
+        "year",
+        "day",
+        "part",
+        "answer",
+        "parse_us",
+        "part_us",
+        "verification",
+        "expected",
+        "error",
+    ];
+
+    /// Records of the parts of `solved` that have an answer, timed out or panicked, `verify`
+    /// returns `None` when the answers can't be checked.
+    pub fn from_solved(
+        year: u32,
+        day: u32,
+        solved: &Solved,
+        verify: impl Fn(u8, &Answer) -> Option<Verdict>,
+    ) -> Vec<Record> {
+        let record = |part: u8, answer: String, verification, expected, error| {
+            let elapsed = match part {
+                1 => solved.timings.part1,
+                _ => solved.timings.part2,
+            };
+            Record {
+                year,
+                day,
+                part,
+                answer,
+                parse_us: solved.timings.parse.as_micros(),
+                part_us: elapsed.as_micros(),
+                verification,
+                expected,
+                error,
+            }
+        };
+        let mut records = solved
+            .answers()
+            .map(|(part, answer)| {
+                let verdict = verify(part, answer);
+                let verification = verdict
+                    .as_ref()
+                    .map_or(Verification::Unchecked, Verification::from);
+                let expected = match verdict {
+                    Some(Verdict::Fail { expected }) => Some(expected),
+                    _ => None,
+                };
+                record(part, answer.to_string(), verification, expected, None)
+            })
+            .chain(
+                solved
+                    .timed_out
+                    .iter()
+                    .map(|&part| record(part, String::new(), Verification::Timeout, None, None)),
+            )
+            .chain(solved.failed.iter().map(|(part, panic)| {
+                let error = Some(panic.to_string());
+                record(*part, String::new(), Verification::Failed, None, error)
+            }))
+            .collect::<Vec<_>>();
+        records.sort_by_key(|r| r.part);
+        records
+    }
+
+    fn csv_row(&self) -> [String; 9] {
+        let verification = serde_json::to_value(self.verification).unwrap();
+        [
+            self.year.to_string(),
+            self.day.to_string(),
+            self.part.to_string(),
+            csv_field(&self.answer),
+            self.parse_us.to_string(),
+            self.part_us.to_string(),
+            verification.as_str().unwrap().to_string(),
+            self.expected.as_deref().map_or(String::new(), csv_field),
+            self.error.as_deref().map_or(String::new(), csv_field),
+        ]
+    }
+}
+
+/// Quotes fields that contain separators, quotes or line breaks.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub fn print_json(records: &[Record]) -> anyhow::Result<()> {
+    println!("{}", serde_json::to_string_pretty(records)?);
+    Ok(())
+}
+
+pub fn print_csv(records: &[Record]) {
+    println!("{}", Record::COLUMNS.join(","));
+    for record in records {
+        println!("{}", record.csv_row().join(","));
+    }
+}
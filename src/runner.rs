@@ -0,0 +1,490 @@
+use crate::bench::format_duration;
+use crate::cli::{
+    DaySelection, ExamplesArgs, ExtractArgs, FetchArgs, RunArgs, SubmitArgs, VerifyArgs,
+};
+use crate::report::{self, Format, Record};
+use crate::table::print_table;
+use anyhow::{bail, Context};
+use aoc2023::{
+    extract_examples, Answer, AnswerStore, ExampleStore, InputManager, Overrides, Part, Solved,
+    Submission, SubmissionLog, SubmitOutcome, Verdict, Workers,
+};
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+fn read_input(args: &RunArgs, inputs: &InputManager, day: u32) -> anyhow::Result<String> {
+    match args.input.as_deref() {
+        Some(path) if path.as_os_str() == "-" => {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .context("failed to read input from stdin")?;
+            Ok(input)
+        }
+        Some(path) => std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read input from {}", path.display())),
+        None if args.example => Ok(ExampleStore::load(args.year)?.input(day)?),
+        None => Ok(inputs.get(args.year, day)?),
+    }
+}
+
+pub fn run(args: &RunArgs, inputs: &InputManager, answer_dir: &Path) -> anyhow::Result<()> {
+    let days = match &args.day {
+        Some(selection) => selection.days(&aoc2023::solved_days(args.year)),
+        None => Vec::from_iter(aoc2023::latest(args.year)),
+    };
+    match (&args.day, days.is_empty()) {
+        (Some(_), true) => bail!("no solutions for the selected days of {}", args.year),
+        (None, true) => bail!("no solutions for {}", args.year),
+        _ => {}
+    }
+    if args.input.is_some() && days.len() != 1 {
+        bail!("--input can only be used when solving a single day");
+    }
+    if !args.params.is_empty() && days.len() != 1 {
+        bail!("--param can only be used when solving a single day");
+    }
+    let params = args.params.iter().cloned().collect::<Overrides>();
+    let real_input = args.input.is_none() && !args.example;
+    if real_input {
+        inputs.prefetch(args.year, &days)?;
+    }
+    // answers with other parameters aren't the puzzle's answers
+    let checked = real_input && params.is_empty();
+    let log = SubmissionLog::open(answer_dir, args.year)?;
+    let store = AnswerStore::open(answer_dir, args.year)?;
+
+    // days wait for their answers on threads of their own, all solving happens on `workers` so
+    // `jobs` bounds the threads of days that use rayon themselves too
+    let workers = Workers::new(args.jobs)?;
+    let results = std::thread::scope(|scope| {
+        let (params, workers) = (&params, &workers);
+        let solving = days
+            .iter()
+            .map(|&day| {
+                scope.spawn(move || {
+                    let input = read_input(args, inputs, day)?;
+                    let (year, part, timeout) = (args.year, args.part, args.timeout);
+                    aoc2023::solve_day_timeout(
+                        year,
+                        day,
+                        input.trim(),
+                        part,
+                        params,
+                        timeout,
+                        workers,
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        solving
+            .into_iter()
+            .map(|day| {
+                day.join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let mut rows = Vec::new();
+    let mut records = Vec::new();
+    let mut failed = 0;
+    for (&day, solved) in days.iter().zip(results) {
+        // a panicking part fails on its own, other errors stop the run
+        let solved = solved?;
+        for (part, panic) in &solved.failed {
+            eprintln!("error: day {day} part {part} {panic}");
+        }
+        if !solved.failed.is_empty() {
+            failed += 1;
+        }
+        // earlier submissions were checked against the real input only
+        for (part, answer) in solved.answers().filter(|_| checked) {
+            if let Some(wrong) = log.check(day, part, answer) {
+                eprintln!("warning: day {day} part {part}: {answer} {wrong}");
+            }
+        }
+        if args.format != Format::Table {
+            records.extend(Record::from_solved(
+                args.year,
+                day,
+                &solved,
+                |part, answer| checked.then(|| store.verify(day, part, answer)),
+            ));
+            continue;
+        }
+        if days.len() == 1 {
+            let answers = [1, 2]
+                .into_iter()
+                .filter_map(|part| shown(&solved, part))
+                .collect::<Vec<_>>();
+            match &answers[..] {
+                [answer] => println!("Day{day}: {answer}"),
+                _ => println!("Day{day}: ({})", answers.join(", ")),
+            }
+            continue;
+        }
+        let verdicts =
+            [(1, &solved.part1), (2, &solved.part2)].map(|(part, answer)| match answer {
+                _ if solved.timed_out.contains(&part) => "TIMEOUT".to_string(),
+                _ if solved.failure(part).is_some() => "FAILED".to_string(),
+                Some(answer) if checked && *answer != Answer::None => {
+                    store.verify(day, part, answer).to_string()
+                }
+                _ => "-".to_string(),
+            });
+        let answer = |part| shown(&solved, part).unwrap_or("-".to_string());
+        rows.push(vec![
+            day.to_string(),
+            answer(1),
+            answer(2),
+            format_duration(solved.timings.total()),
+            verdicts.join(" / "),
+        ]);
+    }
+    match args.format {
+        Format::Table if !rows.is_empty() => {
+            let header = ["day", "part 1", "part 2", "runtime", "verification"];
+            print_table(&header, &rows);
+        }
+        Format::Table => {}
+        Format::Json => report::print_json(&records)?,
+        Format::Csv => report::print_csv(&records),
+    }
+    problems(&[(failed, "day(s) failed")])
+}
+
+/// What to show for a part: its answer, `TIMEOUT`, `FAILED`, or `None` when it wasn't solved.
+fn shown(solved: &Solved, part: u8) -> Option<String> {
+    if solved.timed_out.contains(&part) {
+        return Some("TIMEOUT".to_string());
+    }
+    if solved.failure(part).is_some() {
+        return Some("FAILED".to_string());
+    }
+    let answer = match part {
+        1 => &solved.part1,
+        _ => &solved.part2,
+    };
+    answer.as_ref().map(|a| a.to_string())
+}
+
+/// Solves every selected day on the real input and compares the answers with the answer store.
+pub fn verify(args: &VerifyArgs, inputs: &InputManager, answer_dir: &Path) -> anyhow::Result<()> {
+    let mut store = AnswerStore::open(answer_dir, args.year)?;
+    let log = SubmissionLog::open(answer_dir, args.year)?;
+    let (mut failures, mut failed, mut timeouts) = (0, 0, 0);
+    let days = args
+        .day
+        .as_ref()
+        .unwrap_or(&DaySelection::All)
+        .days(&aoc2023::solved_days(args.year));
+    inputs.prefetch(args.year, &days)?;
+    let workers = Workers::new(0)?;
+    for day in days {
+        let input = inputs.get(args.year, day)?;
+        let solved = aoc2023::solve_day_timeout(
+            args.year,
+            day,
+            input.trim(),
+            Part::Both,
+            &Overrides::default(),
+            args.timeout,
+            &workers,
+        )?;
+        for (part, panic) in &solved.failed {
+            println!("Day{day} part {part}: FAILED {panic}");
+            failed += 1;
+        }
+        for &part in &solved.timed_out {
+            println!("Day{day} part {part}: TIMEOUT");
+            timeouts += 1;
+        }
+        for (part, answer) in solved.answers() {
+            let verdict = store.verify(day, part, answer);
+            if let Verdict::Fail { .. } = verdict {
+                failures += 1;
+            }
+            print_verdict(
+                &mut io::stdout(),
+                &format!("Day{day} part {part}"),
+                &verdict,
+                answer,
+            )?;
+            if verdict != Verdict::Unknown {
+                continue;
+            }
+            match log.check(day, part, answer) {
+                Some(wrong) => eprintln!("warning: day {day} part {part}: {answer} {wrong}"),
+                None if args.record => store.record(day, part, answer),
+                None => {}
+            }
+        }
+    }
+    if args.record {
+        store.save()?;
+    }
+    problems(&[
+        (failures, "answer(s) don't match the answer store"),
+        (failed, "part(s) failed"),
+        (timeouts, "part(s) timed out"),
+    ])
+}
+
+/// Fails with the problems that happened at least once, e.g. "2 day(s) failed".
+fn problems(counts: &[(usize, &str)]) -> anyhow::Result<()> {
+    let problems = counts
+        .iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, problem)| format!("{count} {problem}"))
+        .collect::<Vec<_>>();
+    if !problems.is_empty() {
+        bail!("{}", problems.join(", "));
+    }
+    Ok(())
+}
+
+fn print_verdict(
+    out: &mut dyn Write,
+    label: &str,
+    verdict: &Verdict,
+    answer: &Answer,
+) -> io::Result<()> {
+    match verdict {
+        Verdict::Fail { expected } => {
+            writeln!(out, "{label}: {verdict}")?;
+            writeln!(out, "  - expected: {expected}")?;
+            writeln!(out, "  + actual:   {answer}")
+        }
+        _ => writeln!(out, "{label}: {verdict} {answer}"),
+    }
+}
+
+/// Solves every example of the selected days and compares the answers with the expected ones,
+/// the verdicts go to `out`.
+pub fn examples(args: &ExamplesArgs, out: &mut dyn Write) -> anyhow::Result<()> {
+    let store = ExampleStore::load(args.year)?;
+    let (mut failures, mut failed) = (0, 0);
+    let days = args
+        .day
+        .as_ref()
+        .unwrap_or(&DaySelection::All)
+        .days(&aoc2023::solved_days(args.year));
+    for day in days {
+        let examples = store.examples(day)?;
+        if examples.is_empty() {
+            eprintln!("Day{day}: no examples in {}", store.dir().display());
+        }
+        for example in examples {
+            let expected = (
+                example.answers.part1.is_some(),
+                example.answers.part2.is_some(),
+            );
+            // examples often only cover one of the parts
+            let part = match expected {
+                (true, true) => Part::Both,
+                (true, false) => Part::One,
+                (false, true) => Part::Two,
+                (false, false) => {
+                    eprintln!("Day{day} {}: no expected answers", example.name);
+                    continue;
+                }
+            };
+            let params = &example.answers.params;
+            let solved = aoc2023::solve_day(args.year, day, example.input.trim(), part, params)?;
+            for (part, panic) in &solved.failed {
+                writeln!(out, "Day{day} part {part} {}: FAILED {panic}", example.name)?;
+                failed += 1;
+            }
+            for (part, answer) in solved.answers() {
+                let verdict = match example.answers.expected(part) {
+                    Some(expected) if expected == answer.to_string() => Verdict::Pass,
+                    Some(expected) => Verdict::Fail {
+                        expected: expected.to_string(),
+                    },
+                    None => Verdict::Unknown,
+                };
+                if let Verdict::Fail { .. } = verdict {
+                    failures += 1;
+                }
+                print_verdict(
+                    out,
+                    &format!("Day{day} part {part} {}", example.name),
+                    &verdict,
+                    answer,
+                )?;
+            }
+        }
+    }
+    problems(&[
+        (failures, "example answer(s) are wrong"),
+        (failed, "example part(s) failed"),
+    ])
+}
+
+/// Writes the examples of a puzzle page to `{day}.ex`, `{day}-2.ex`, .. in the example store.
+pub fn extract(args: &ExtractArgs, inputs: &InputManager) -> anyhow::Result<()> {
+    let (year, day) = (args.year, args.day);
+    let page = match &args.page {
+        Some(path) => std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?,
+        None if inputs.is_offline() => {
+            bail!("can't download the puzzle page in offline mode, pass --page")
+        }
+        None => inputs.client().download_puzzle(year, day)?,
+    };
+    let examples = extract_examples(&page);
+    if examples.is_empty() {
+        bail!("no examples found in the puzzle page of {year} day {day}");
+    }
+    let names = (1..=examples.len())
+        .map(|i| match i {
+            1 => format!("{day}.ex"),
+            _ => format!("{day}-{i}.ex"),
+        })
+        .collect::<Vec<_>>();
+    let mut store = ExampleStore::load(year)?;
+    let existing = names
+        .iter()
+        .filter(|name| store.dir().join(name).exists())
+        .collect::<Vec<_>>();
+    if !args.force && !existing.is_empty() {
+        bail!(
+            "{existing:?} already exist in {}, use --force to overwrite",
+            store.dir().display()
+        );
+    }
+    for (name, (input, answers)) in names.iter().zip(examples) {
+        let expected = |part: u8| answers.expected(part).unwrap_or("-").to_string();
+        println!("{name}: part 1 {}, part 2 {}", expected(1), expected(2));
+        store.add(name, &input, answers)?;
+    }
+    store.save()
+}
+
+/// One row per year with solutions, counting what's known about the solved days.
+pub fn summary(inputs: &InputManager, answer_dir: &Path) -> anyhow::Result<()> {
+    let header = ["year", "solved days", "stars", "inputs", "examples"];
+    let mut rows = Vec::new();
+    let mut totals = [0; 4];
+    for year in aoc2023::solved_years() {
+        let days = aoc2023::solved_days(year);
+        let answers = AnswerStore::open(answer_dir, year)?;
+        let examples = ExampleStore::load(year)?;
+        let stars = days
+            .iter()
+            .flat_map(|&day| [(day, 1), (day, 2)])
+            .filter(|&(day, part)| answers.expected(day, part).is_some())
+            .count();
+        let cached = days.len() - inputs.missing(year, &days).len();
+        let mut with_examples = 0;
+        for &day in days.iter() {
+            if !examples.names(day)?.is_empty() {
+                with_examples += 1;
+            }
+        }
+        let counts = [days.len(), stars, cached, with_examples];
+        totals.iter_mut().zip(counts).for_each(|(t, c)| *t += c);
+        rows.push(summary_row(year.to_string(), counts));
+    }
+    rows.push(summary_row("total".to_string(), totals));
+    print_table(&header, &rows);
+    Ok(())
+}
+
+fn summary_row(label: String, [days, stars, cached, examples]: [usize; 4]) -> Vec<String> {
+    vec![
+        label,
+        days.to_string(),
+        format!("{stars}/{}", days * 2),
+        format!("{cached}/{days}"),
+        format!("{examples}/{days}"),
+    ]
+}
+
+pub fn fetch(args: &FetchArgs, inputs: &InputManager) -> anyhow::Result<()> {
+    let all = (1..=25).collect::<Vec<_>>();
+    let days = args.day.as_ref().unwrap_or(&DaySelection::All).days(&all);
+    let missing = inputs.missing(args.year, &days);
+    // an expired session fails once here instead of on every download
+    if missing.len() > 1 && !inputs.is_offline() {
+        let user = inputs.client().whoami()?;
+        eprintln!("downloading {} inputs as {user}", missing.len());
+    }
+    let downloaded = inputs.prefetch(args.year, &days)?;
+    println!(
+        "{} input(s) downloaded, {} already cached in {}",
+        downloaded.len(),
+        days.len() - downloaded.len(),
+        inputs.dir().display()
+    );
+    Ok(())
+}
+
+/// Checks the session of the selected profile with the website.
+pub fn whoami(inputs: &InputManager) -> anyhow::Result<()> {
+    let profile = inputs.client().profile().unwrap_or("default");
+    let user = inputs.client().whoami()?;
+    println!("{user} (profile {profile})");
+    Ok(())
+}
+
+/// Submits an answer unless the outcome is already known from the answer store or an earlier submission.
+pub fn submit(args: &SubmitArgs, inputs: &InputManager, answer_dir: &Path) -> anyhow::Result<()> {
+    let (year, day, part) = (args.year, args.day, args.part);
+    if inputs.is_offline() {
+        bail!("can't submit answers in offline mode");
+    }
+    let answer = match &args.answer {
+        Some(answer) => answer.trim().to_string(),
+        None => {
+            let input = inputs.get(year, day)?;
+            let solved =
+                aoc2023::solve_day(year, day, input.trim(), Part::Both, &Overrides::default())?;
+            let answer = solved.answers().find(|(p, _)| *p == part);
+            match (answer, solved.failure(part)) {
+                (Some((_, answer)), _) => answer.to_string(),
+                (None, Some(panic)) => bail!("day {day} part {part} {panic}"),
+                (None, None) => bail!("day {day} has no answer for part {part}"),
+            }
+        }
+    };
+    let mut store = AnswerStore::open(answer_dir, year)?;
+    match store.expected(day, part) {
+        Some(expected) if expected == answer => {
+            println!("Day{day} part {part}: {answer} is already known to be correct");
+            return Ok(());
+        }
+        Some(expected) => bail!("the answer store says day {day} part {part} is {expected}"),
+        None => {}
+    }
+    let mut log = SubmissionLog::open(answer_dir, year)?;
+    if let Some(wrong) = log.check(day, part, &Answer::Text(answer.clone())) {
+        bail!("not submitting {answer}, it {wrong}");
+    }
+
+    let outcome = inputs.client().submit_answer(year, day, part, &answer)?;
+    println!("Day{day} part {part}: {answer} is {outcome}");
+    let hint = match outcome {
+        SubmitOutcome::Correct => {
+            store.record(day, part, &Answer::Text(answer.clone()));
+            store.save()?;
+            None
+        }
+        SubmitOutcome::Incorrect(hint) => hint,
+        SubmitOutcome::AlreadySolved => return Ok(()),
+        SubmitOutcome::RateLimited { .. } => bail!("the answer wasn't checked, try again later"),
+    };
+    let correct = outcome == SubmitOutcome::Correct;
+    let submission = Submission {
+        answer,
+        correct,
+        hint,
+    };
+    log.record(day, part, submission);
+    log.save()?;
+    if !correct {
+        bail!("wrong answer");
+    }
+    Ok(())
+}
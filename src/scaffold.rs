@@ -0,0 +1,75 @@
+use crate::cli::NewDayArgs;
+use anyhow::{bail, Context};
+use aoc2023::{ExampleAnswers, ExampleStore};
+use regex::Regex;
+use std::path::Path;
+
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+fn read(path: &Path) -> anyhow::Result<String> {
+    std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))
+}
+
+fn write(path: &Path, content: &str) -> anyhow::Result<()> {
+    std::fs::write(path, content).with_context(|| format!("failed to write {}", path.display()))
+}
+
+/// Adds `day{day} => {day}` to the year's block of the `example_tests!` list, unless it's already
+/// there. Years without a block get a new one.
+fn add_test(tests: &str, year: u32, day: u32) -> anyhow::Result<Option<String>> {
+    let Some(start) = tests.find("example_tests! {") else {
+        bail!("example_tests! list not found");
+    };
+    let end = start + tests[start..].find("\n}").unwrap();
+    let header = format!("    y{year} => {year} {{");
+    let Some(block) = tests[start..end].find(&header).map(|i| start + i) else {
+        return Ok(Some(format!(
+            "{}\n{header}\n        day{day} => {day},\n    }}{}",
+            &tests[..end],
+            &tests[end..]
+        )));
+    };
+    let block_end = block + tests[block..].find("\n    }").unwrap();
+    let entry = Regex::new(&format!(r"\bday{day} =>")).unwrap();
+    if entry.is_match(&tests[block..block_end]) {
+        return Ok(None);
+    }
+    Ok(Some(format!(
+        "{}\n        day{day} => {day},{}",
+        &tests[..block_end],
+        &tests[block_end..]
+    )))
+}
+
+/// Generates `src/years/y{year}/day{day}.rs` from the template, with an empty example and an
+/// example test.
+///
+/// build.rs registers the new module, there's nothing to edit by hand.
+pub fn new_day(args: &NewDayArgs) -> anyhow::Result<()> {
+    let (root, year, day) = (&args.root, args.year, args.day);
+    let module = root.join(format!("src/years/y{year}/day{day}.rs"));
+    if module.exists() {
+        bail!("{} already exists, not overwriting it", module.display());
+    }
+    let tests = root.join("tests/examples.rs");
+    let with_test = add_test(&read(&tests)?, year, day)?;
+
+    std::fs::create_dir_all(module.parent().unwrap())?;
+    write(&module, &TEMPLATE.replace("{day}", &day.to_string()))?;
+    println!("created {}", module.display());
+    if let Some(with_test) = with_test {
+        write(&tests, &with_test)?;
+        println!("added the day {day} example test to {}", tests.display());
+    }
+    let mut examples = ExampleStore::open(root.join("examples").join(year.to_string()))?;
+    let name = format!("{day}.ex");
+    if !examples.dir().join(&name).exists() {
+        examples.add(&name, "", ExampleAnswers::default())?;
+        examples.save()?;
+        println!(
+            "created {}, fill in the example and its answers",
+            examples.dir().join(&name).display()
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use itertools::Itertools;
+
+/// Prints left-aligned columns separated by two spaces.
+pub fn print_table(header: &[&str], rows: &[Vec<String>]) {
+    let widths = (0..header.len())
+        .map(|i| {
+            rows.iter()
+                .map(|r| r[i].chars().count())
+                .chain([header[i].chars().count()])
+                .max()
+                .unwrap()
+        })
+        .collect_vec();
+    let print_row = |cells: &[&str]| {
+        let line = cells
+            .iter()
+            .zip(&widths)
+            .map(|(c, w)| format!("{c:<w$}"))
+            .join("  ");
+        println!("{}", line.trim_end());
+    };
+    print_row(header);
+    for row in rows {
+        print_row(&row.iter().map(|c| c.as_str()).collect_vec());
+    }
+}
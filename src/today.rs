@@ -0,0 +1,57 @@
+use crate::cli::{DaySelection, ExamplesArgs, RunArgs, TodayArgs};
+use crate::report::Format;
+use crate::runner;
+use anyhow::bail;
+use aoc2023::clock::{self, Clock};
+use aoc2023::{InputManager, Part};
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Runs the puzzle of the current Advent of Code day, or with `--wait` counts down to the next
+/// puzzle's unlock and runs that one. The threads, timeout and output format come from
+/// `defaults`, the run flags with the config applied. Only a table shares stdout with the puzzle
+/// and the example verdicts, JSON and CSV get it to themselves.
+pub fn today(
+    args: &TodayArgs,
+    defaults: &RunArgs,
+    inputs: &InputManager,
+    answer_dir: &Path,
+    clock: &dyn Clock,
+) -> anyhow::Result<()> {
+    let (year, day) = clock::todays_puzzle(clock, args.puzzle, args.wait, &mut io::stderr())?;
+    let mut out: Box<dyn Write> = match defaults.format {
+        Format::Table => Box::new(io::stdout()),
+        Format::Json | Format::Csv => Box::new(io::stderr()),
+    };
+    writeln!(out, "{year} day {day}")?;
+    inputs.fetch_unlocked(clock, year, day)?;
+    if aoc2023::solver(year, day).is_none() {
+        bail!(
+            "no solution for {year} day {day} yet, the input is in {}, `new-day -y {year} -d {day}` starts one",
+            inputs.path(year, day).display()
+        );
+    }
+    let selection = DaySelection::Range(day..=day);
+    runner::examples(
+        &ExamplesArgs {
+            year,
+            day: Some(selection.clone()),
+        },
+        &mut out,
+    )?;
+    runner::run(
+        &RunArgs {
+            year,
+            day: Some(selection),
+            part: Part::Both,
+            example: false,
+            input: None,
+            jobs: defaults.jobs,
+            format: defaults.format,
+            timeout: defaults.timeout,
+            params: vec![],
+        },
+        inputs,
+        answer_dir,
+    )
+}
@@ -0,0 +1,195 @@
+use crate::cli::WatchArgs;
+use anyhow::{bail, Context};
+use aoc2023::{ExampleStore, InputManager, PROJECT_ROOT};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+
+/// Modification time and length of every watched file that exists.
+type Snapshot = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+struct Watcher<'a> {
+    year: u32,
+    day: u32,
+    inputs: &'a InputManager,
+    /// The binary that's running, cargo rebuilds it in place when the source changes
+    exe: PathBuf,
+}
+
+impl Watcher<'_> {
+    fn source(&self) -> PathBuf {
+        Path::new(PROJECT_ROOT).join(format!("src/years/y{}/day{}.rs", self.year, self.day))
+    }
+
+    fn files(&self) -> anyhow::Result<Vec<PathBuf>> {
+        let examples = ExampleStore::load(self.year)?;
+        let mut files = vec![
+            self.source(),
+            self.inputs.path(self.year, self.day),
+            examples.dir().join("answers.toml"),
+        ];
+        for name in examples.names(self.day)? {
+            files.push(examples.dir().join(name));
+        }
+        Ok(files)
+    }
+
+    fn snapshot(&self) -> anyhow::Result<Snapshot> {
+        Ok(self
+            .files()?
+            .into_iter()
+            .filter_map(|path| {
+                let meta = std::fs::metadata(&path).ok()?;
+                Some((path, (meta.modified().ok()?, meta.len())))
+            })
+            .collect())
+    }
+
+    /// Runs a subcommand of the binary for the watched day, returning its stdout and stderr lines
+    /// and whether it succeeded.
+    fn child(&self, command: &str) -> anyhow::Result<(Vec<String>, bool)> {
+        let mut cmd = Command::new(&self.exe);
+        cmd.args(["--input-dir".as_ref(), self.inputs.dir().as_os_str()])
+            .args(["--base-url", self.inputs.client().base_url()])
+            .args([
+                command,
+                "-y",
+                &self.year.to_string(),
+                "-d",
+                &self.day.to_string(),
+            ]);
+        if self.inputs.is_offline() {
+            cmd.arg("--offline");
+        }
+        if let Some(profile) = self.inputs.client().profile() {
+            cmd.args(["--profile", profile]);
+        }
+        let output = cmd
+            .output()
+            .with_context(|| format!("failed to run {}", self.exe.display()))?;
+        let lines = [output.stdout, output.stderr]
+            .iter()
+            .flat_map(|out| {
+                String::from_utf8_lossy(out)
+                    .lines()
+                    .map(String::from)
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        Ok((lines, output.status.success()))
+    }
+
+    /// The examples, then the real input once the examples pass.
+    fn check(&self) -> anyhow::Result<Vec<String>> {
+        let (mut lines, passed) = self.child("examples")?;
+        if !passed {
+            lines.push("skipping the real input until the examples pass".to_string());
+            return Ok(lines);
+        }
+        lines.extend(self.child("run")?.0);
+        Ok(lines)
+    }
+
+    /// Rebuilds the binary with cargo, returning the compiler output when the build fails.
+    fn build(&self) -> anyhow::Result<Option<Vec<String>>> {
+        let mut cmd = Command::new(std::env::var_os("CARGO").unwrap_or("cargo".into()));
+        cmd.current_dir(PROJECT_ROOT).args(["build", "--quiet"]);
+        if !cfg!(debug_assertions) {
+            cmd.arg("--release");
+        }
+        let output = cmd.output().context("failed to run cargo build")?;
+        if output.status.success() {
+            return Ok(None);
+        }
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Ok(Some(stderr.lines().map(String::from).collect()))
+    }
+}
+
+/// Line diff of two results: unchanged lines are indented, removed lines start with `-` and added
+/// lines with `+`.
+fn diff(old: &[String], new: &[String]) -> Vec<String> {
+    // longest common subsequence of the suffixes
+    let mut lcs = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::new();
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(format!("  {}", old[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if j == new.len() || (i < old.len() && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(format!("- {}", old[i]));
+            i += 1;
+        } else {
+            lines.push(format!("+ {}", new[j]));
+            j += 1;
+        }
+    }
+    lines
+}
+
+/// Re-runs a day whenever its source, input or examples change, until interrupted.
+pub fn watch(args: &WatchArgs, inputs: &InputManager) -> anyhow::Result<()> {
+    let Some(day) = args.day.or_else(|| aoc2023::latest(args.year)) else {
+        bail!("no solutions for {}", args.year);
+    };
+    if aoc2023::solver(args.year, day).is_none() {
+        bail!("no solution for {} day {day}", args.year);
+    }
+    let watcher = Watcher {
+        year: args.year,
+        day,
+        inputs,
+        exe: std::env::current_exe().context("failed to find the runner binary")?,
+    };
+    let interval = Duration::from_millis(args.interval);
+    let mut snapshot = watcher.snapshot()?;
+    let mut last: Option<Vec<String>> = None;
+    loop {
+        let result = watcher.check()?;
+        match &last {
+            None => result.iter().for_each(|line| println!("{line}")),
+            Some(last) if *last == result => println!("no change"),
+            Some(last) => diff(last, &result)
+                .iter()
+                .for_each(|line| println!("{line}")),
+        }
+        last = Some(result);
+        println!("watching {} files of day {day}", snapshot.len());
+
+        loop {
+            std::thread::sleep(interval);
+            let current = watcher.snapshot()?;
+            if current == snapshot {
+                continue;
+            }
+            let source = watcher.source();
+            let rebuild = current.get(&source) != snapshot.get(&source);
+            snapshot = current;
+            if !rebuild {
+                break;
+            }
+            println!("{} changed, rebuilding", source.display());
+            match watcher.build()? {
+                None => break,
+                Some(errors) => {
+                    errors.iter().for_each(|line| eprintln!("{line}"));
+                    println!(
+                        "build failed, watching {} files of day {day}",
+                        snapshot.len()
+                    );
+                }
+            }
+        }
+    }
+}
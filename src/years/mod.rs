@@ -0,0 +1,5 @@
+//! Solutions by year, build.rs declares a `y{year}` module with every `y{year}/day{day}.rs`.
+
+use crate::days::{Answer, Solution, Solver};
+
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
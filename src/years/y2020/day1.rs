@@ -0,0 +1,31 @@
+use super::{Answer, Solution};
+use itertools::Itertools;
+
+/// The product of the `size` entries that sum to 2020.
+fn solve(entries: &[i64], size: usize) -> i64 {
+    entries
+        .iter()
+        .combinations(size)
+        .find(|c| c.iter().copied().sum::<i64>() == 2020)
+        .map(|c| c.into_iter().product())
+        .unwrap()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed<'a> = Vec<i64>;
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.lines().map(|l| l.trim().parse().unwrap()).collect()
+    }
+
+    fn part1(entries: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve(entries, 2).into()
+    }
+
+    fn part2(entries: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve(entries, 3).into()
+    }
+}
@@ -1,3 +1,4 @@
+use super::{Answer, Solution};
 use itertools::{self, Itertools};
 
 fn get_digit(a: &str) -> Option<char> {
@@ -43,6 +44,21 @@ fn solve_1(input: &str) -> usize {
         .sum()
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    (solve_1(input), solve_2(input))
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed<'a> = &'a str;
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_1(input).into()
+    }
+
+    fn part2(input: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_2(input).into()
+    }
 }
@@ -1,3 +1,5 @@
+use super::{Answer, Solution};
+use crate::days::helpers::parse_grid;
 use itertools::Itertools;
 use num::traits::Euclid;
 use std::collections::{HashSet, VecDeque};
@@ -10,7 +12,7 @@ enum Direction {
     East,
 }
 
-fn include(grid: &Vec<Vec<char>>, pos: (usize, usize), dir: Direction) -> Option<(usize, usize)> {
+fn include(grid: &[Vec<char>], pos: (usize, usize), dir: Direction) -> Option<(usize, usize)> {
     let (x, y) = match dir {
         Direction::North => (pos.0.saturating_sub(1), pos.1),
         Direction::South => (pos.0 + 1, pos.1),
@@ -54,7 +56,7 @@ fn include(grid: &Vec<Vec<char>>, pos: (usize, usize), dir: Direction) -> Option
     }
 }
 
-fn neighbours(grid: &Vec<Vec<char>>, pos: (usize, usize)) -> Vec<(usize, usize)> {
+fn neighbours(grid: &[Vec<char>], pos: (usize, usize)) -> Vec<(usize, usize)> {
     let mut nes = Vec::new();
     match grid[pos.0][pos.1] {
         'F' | 'S' => {
@@ -86,7 +88,7 @@ fn neighbours(grid: &Vec<Vec<char>>, pos: (usize, usize)) -> Vec<(usize, usize)>
     nes.into_iter().flatten().collect_vec()
 }
 
-fn bfs(grid: &Vec<Vec<char>>, start: (usize, usize)) -> (HashSet<(usize, usize)>, usize) {
+fn bfs(grid: &[Vec<char>], start: (usize, usize)) -> (HashSet<(usize, usize)>, usize) {
     let mut explored = HashSet::new();
     let mut frontier = VecDeque::new();
     frontier.push_back((start, 0));
@@ -104,7 +106,7 @@ fn bfs(grid: &Vec<Vec<char>>, start: (usize, usize)) -> (HashSet<(usize, usize)>
     (explored, max_g)
 }
 
-fn solve_2(grid: &Vec<Vec<char>>, explored: &HashSet<(usize, usize)>) -> usize {
+fn solve_2(grid: &[Vec<char>], explored: &HashSet<(usize, usize)>) -> usize {
     grid.iter()
         .enumerate()
         .flat_map(|(i, l)| l.iter().enumerate().map(move |(j, _)| (i, j)))
@@ -124,13 +126,29 @@ fn solve_2(grid: &Vec<Vec<char>>, explored: &HashSet<(usize, usize)>) -> usize {
         .count()
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    let grid = input.lines().map(|l| l.chars().collect_vec()).collect_vec();
-    let start = grid
-        .iter()
-        .enumerate()
-        .find_map(|(i, l)| l.iter().position(|c| *c == 'S').map(|j| (i, j)))
-        .unwrap();
-    let (explored, s1) = bfs(&grid, start);
-    (s1, solve_2(&grid, &explored))
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed<'a> = (Vec<Vec<char>>, (usize, usize));
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        let grid = parse_grid(input);
+        let start = grid
+            .iter()
+            .enumerate()
+            .find_map(|(i, l)| l.iter().position(|c| *c == 'S').map(|j| (i, j)))
+            .unwrap();
+        (grid, start)
+    }
+
+    fn part1((grid, start): &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        let (_, s1) = bfs(grid, *start);
+        s1.into()
+    }
+
+    fn part2((grid, start): &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        let (explored, _) = bfs(grid, *start);
+        solve_2(grid, &explored).into()
+    }
 }
@@ -1,18 +1,9 @@
+use super::{Answer, Solution};
+use crate::days::helpers::{manhattan_distance, parse_grid, transpose};
+use crate::days::params::params;
 use itertools::Itertools;
 
-fn transpose(matrix: &Vec<Vec<char>>) -> Vec<Vec<char>> {
-    let rows = matrix.len();
-    let cols = matrix[0].len();
-    let mut result = vec![vec![' '; rows]; cols];
-    for i in 0..rows {
-        for j in 0..cols {
-            result[j][i] = matrix[i][j];
-        }
-    }
-    result
-}
-
-fn empty_r(grid: &Vec<Vec<char>>) -> Vec<usize> {
+fn empty_r(grid: &[Vec<char>]) -> Vec<usize> {
     grid.iter()
         .enumerate()
         .flat_map(|(i, l)| match l.iter().all(|&c| c == '.') {
@@ -22,11 +13,7 @@ fn empty_r(grid: &Vec<Vec<char>>) -> Vec<usize> {
         .collect()
 }
 
-fn manhatten_distance(p1: (usize, usize), p2: (usize, usize)) -> usize {
-    p1.0.abs_diff(p2.0) + p1.1.abs_diff(p2.1)
-}
-
-fn solve_puzzle(grid: &Vec<Vec<char>>, expansion_factor: usize) -> usize {
+fn solve_puzzle(grid: &[Vec<char>], expansion_factor: usize) -> usize {
     let empty_rows = empty_r(grid);
     let empty_cols = empty_r(&transpose(grid));
     grid.iter()
@@ -39,7 +26,7 @@ fn solve_puzzle(grid: &Vec<Vec<char>>, expansion_factor: usize) -> usize {
         })
         .combinations(2)
         .map(|x| {
-            let mut md = manhatten_distance(x[0], x[1]);
+            let mut md = manhattan_distance(x[0], x[1]);
             empty_rows.iter().for_each(|er| {
                 if x[0].0.min(x[1].0) < *er && x[0].0.max(x[1].0) > *er {
                     md += expansion_factor - 1
@@ -55,7 +42,30 @@ fn solve_puzzle(grid: &Vec<Vec<char>>, expansion_factor: usize) -> usize {
         .sum()
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    let grid = input.lines().map(|l| l.chars().collect_vec()).collect_vec();
-    (solve_puzzle(&grid, 2), solve_puzzle(&grid, 1_000_000))
+params! {
+    pub struct Day11Params {
+        /// How many times wider empty rows and columns get in part 1
+        expansion1: usize = 2,
+        /// The same for part 2, the example uses 10 and 100
+        expansion2: usize = 1_000_000,
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed<'a> = Vec<Vec<char>>;
+    type Params = Day11Params;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_grid(input)
+    }
+
+    fn part1(grid: &Self::Parsed<'_>, params: &Self::Params) -> Answer {
+        solve_puzzle(grid, params.expansion1).into()
+    }
+
+    fn part2(grid: &Self::Parsed<'_>, params: &Self::Params) -> Answer {
+        solve_puzzle(grid, params.expansion2).into()
+    }
 }
@@ -1,3 +1,4 @@
+use super::{Answer, Solution};
 use itertools::Itertools;
 use std::collections::HashMap;
 
@@ -11,8 +12,7 @@ fn parse(input: &str) -> (Vec<char>, Vec<usize>) {
 
 fn unfold(seq: &[char], rec: &[usize]) -> (Vec<char>, Vec<usize>) {
     (
-        std::iter::repeat(seq.iter().collect::<String>())
-            .take(5)
+        std::iter::repeat_n(seq.iter().collect::<String>(), 5)
             .join("?")
             .chars()
             .collect(),
@@ -29,7 +29,7 @@ fn arrangements_brute(seq: &[char], rec: &[usize]) -> usize {
     (0..unknowns)
         .map(|_| vec!['.', '#'])
         .multi_cartesian_product()
-        .filter(|mcp| count_c(mcp, '#') + count_c(seq, '#') == rec.iter().sum())
+        .filter(|mcp| count_c(mcp, '#') + count_c(seq, '#') == rec.iter().sum::<usize>())
         .map(|mcp| {
             let mut it = mcp.iter();
             seq.iter()
@@ -62,9 +62,9 @@ fn arrangements_memo(
     }
     // stop condition
     if seqi == seq.len() {
-        return if reci == rec.len() && hash_count == 0 {
-            1
-        } else if reci == rec.len() - 1 && rec[reci] == hash_count {
+        return if (reci == rec.len() && hash_count == 0)
+            || (reci == rec.len() - 1 && rec[reci] == hash_count)
+        {
             1
         } else {
             0
@@ -108,7 +108,21 @@ fn solve_2(input: &[(Vec<char>, Vec<usize>)]) -> usize {
         .sum()
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    let parsed = input.trim().lines().map(parse).collect_vec();
-    (solve_1(&parsed), solve_2(&parsed))
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed<'a> = Vec<(Vec<char>, Vec<usize>)>;
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.trim().lines().map(parse).collect_vec()
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_1(parsed).into()
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_2(parsed).into()
+    }
 }
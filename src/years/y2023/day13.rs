@@ -1,8 +1,5 @@
-fn transpose(grid: &[Vec<char>]) -> Vec<Vec<char>> {
-    (0..grid[0].len())
-        .map(|j| (0..grid.len()).map(|i| grid[i][j]).collect())
-        .collect()
-}
+use super::{Answer, Solution};
+use crate::days::helpers::{parse_grid, transpose};
 
 fn find_reflection(grid: &[Vec<char>], symmetric_error: usize) -> usize {
     match (0..grid[0].len() as i32 - 1)
@@ -21,7 +18,7 @@ fn find_reflection(grid: &[Vec<char>], symmetric_error: usize) -> usize {
         .map(|c| c + 1)
     {
         Some(reflection) => reflection as usize,
-        None => 100 * find_reflection(&transpose(grid), symmetric_error) as usize,
+        None => 100 * find_reflection(&transpose(grid), symmetric_error),
     }
 }
 
@@ -36,11 +33,25 @@ fn parse(input: &str) -> Vec<Vec<Vec<char>>> {
     input
         .trim()
         .split("\n\n")
-        .map(|l| l.trim().lines().map(|l| l.chars().collect()).collect())
+        .map(parse_grid)
         .collect()
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    let grids = parse(input);
-    (solve_puzzle(&grids, 0), solve_puzzle(&grids, 1))
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed<'a> = Vec<Vec<Vec<char>>>;
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+
+    fn part1(grids: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_puzzle(grids, 0).into()
+    }
+
+    fn part2(grids: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_puzzle(grids, 1).into()
+    }
 }
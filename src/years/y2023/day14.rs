@@ -1,6 +1,6 @@
-fn parse(input: &str) -> Vec<Vec<char>> {
-    input.lines().map(|l| l.chars().collect()).collect()
-}
+use super::{Answer, Solution};
+use crate::days::helpers::parse_grid;
+use crate::days::params::params;
 
 fn ij_vacant(grid: &[Vec<char>], i: i32, j: i32) -> bool {
     if i < 0 || j < 0 {
@@ -29,7 +29,7 @@ fn find_max_cycle(arr: &[usize], min: usize) -> Option<&[usize]> {
     })
 }
 
-fn next_grid(grid: &mut Vec<Vec<char>>, dirs: &Vec<(i32, i32)>) {
+fn next_grid(grid: &mut Vec<Vec<char>>, dirs: &[(i32, i32)]) {
     dirs.iter().for_each(|dir| loop {
         let mut nxt_grid = grid.clone();
         let moved = grid
@@ -54,13 +54,12 @@ fn next_grid(grid: &mut Vec<Vec<char>>, dirs: &Vec<(i32, i32)>) {
 }
 
 fn solve_1(grid: &mut Vec<Vec<char>>) -> usize {
-    next_grid(grid, &vec![(-1, 0)]);
+    next_grid(grid, &[(-1, 0)]);
     calc_north_load(grid)
 }
 
-fn solve_2(grid: &mut Vec<Vec<char>>) -> usize {
+fn solve_2(grid: &mut Vec<Vec<char>>, n: usize) -> usize {
     let mut i = 0;
-    let n = 1_000_000_000;
     let dirs = vec![(-1, 0), (0, -1), (1, 0), (0, 1)];
     let mut cycle_vec = Vec::new();
     while i < n {
@@ -75,7 +74,28 @@ fn solve_2(grid: &mut Vec<Vec<char>>) -> usize {
     calc_north_load(grid)
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    let mut grid = parse(input);
-    (solve_1(&mut grid), solve_2(&mut grid))
+params! {
+    pub struct Day14Params {
+        /// Spin cycles of part 2
+        cycles: usize = 1_000_000_000,
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed<'a> = Vec<Vec<char>>;
+    type Params = Day14Params;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_grid(input)
+    }
+
+    fn part1(grid: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_1(&mut grid.clone()).into()
+    }
+
+    fn part2(grid: &Self::Parsed<'_>, params: &Self::Params) -> Answer {
+        solve_2(&mut grid.clone(), params.cycles).into()
+    }
 }
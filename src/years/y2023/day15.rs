@@ -1,3 +1,4 @@
+use super::{Answer, Solution};
 use std::collections::HashMap;
 
 fn parse(input: &str) -> Vec<&str> {
@@ -59,7 +60,21 @@ fn solve_2(input: &[&str]) -> usize {
         })
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    let input = parse(input);
-    (solve_1(&input), solve_2(&input))
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed<'a> = Vec<&'a str>;
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_1(input).into()
+    }
+
+    fn part2(input: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_2(input).into()
+    }
 }
@@ -1,3 +1,4 @@
+use super::{Answer, Solution};
 use std::collections::HashSet;
 
 #[derive(PartialEq, Eq, Hash, Clone, Copy)]
@@ -8,7 +9,7 @@ enum Dir {
     East,
 }
 
-struct MirrorMap {
+pub struct MirrorMap {
     grid: Vec<Vec<char>>,
 }
 
@@ -167,7 +168,21 @@ fn solve_2(mirror_map: &MirrorMap) -> usize {
         .unwrap()
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    let mirror_map = MirrorMap::from_str(input);
-    (solve_1(&mirror_map), solve_2(&mirror_map))
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed<'a> = MirrorMap;
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        MirrorMap::from_str(input)
+    }
+
+    fn part1(mirror_map: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_1(mirror_map).into()
+    }
+
+    fn part2(mirror_map: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_2(mirror_map).into()
+    }
 }
@@ -1,3 +1,4 @@
+use super::{Answer, Solution};
 use ahash::AHashSet;
 use itertools::Itertools;
 use std::collections::BinaryHeap;
@@ -51,12 +52,14 @@ impl CrucibleState {
             Direction::East,
         ]
         .into_iter()
-        .filter(|&nd| match (nd, self.d) {
-            (Direction::South, Direction::North) => false,
-            (Direction::North, Direction::South) => false,
-            (Direction::West, Direction::East) => false,
-            (Direction::East, Direction::West) => false,
-            _ => true,
+        .filter(|&nd| {
+            !matches!(
+                (nd, self.d),
+                (Direction::South, Direction::North)
+                    | (Direction::North, Direction::South)
+                    | (Direction::West, Direction::East)
+                    | (Direction::East, Direction::West)
+            )
         })
         .filter(|&nd| {
             if pt2 {
@@ -73,19 +76,18 @@ impl CrucibleState {
         })
         .flat_map(|dr| {
             if dr == self.d {
-                self.try_get(grid, dr).map(|l| CrucibleState::new(
+                self.try_get(grid, dr).map(|l| {
+                    CrucibleState::new(
                         l,
                         self.g + grid[l.0 as usize][l.1 as usize],
                         dr,
                         self.dr + 1,
-                    ))
+                    )
+                })
             } else {
-                self.try_get(grid, dr).map(|l| CrucibleState::new(
-                        l,
-                        self.g + grid[l.0 as usize][l.1 as usize],
-                        dr,
-                        1,
-                    ))
+                self.try_get(grid, dr).map(|l| {
+                    CrucibleState::new(l, self.g + grid[l.0 as usize][l.1 as usize], dr, 1)
+                })
             }
         })
         .collect()
@@ -115,9 +117,7 @@ fn dijkstra(
         if explored.contains(&(s.loc, s.d, s.dr)) {
             continue;
         }
-        if pt2 && s.loc == goal_pos && s.dr >= 4 {
-            return s.g;
-        } else if !pt2 && s.loc == goal_pos {
+        if s.loc == goal_pos && (!pt2 || s.dr >= 4) {
             return s.g;
         }
         for ss in s.successors(grid, pt2) {
@@ -140,16 +140,30 @@ fn solve_puzzle(grid: &[Vec<usize>], pt2: bool) -> usize {
     )
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    let grid = input
-        .trim()
-        .lines()
-        .map(|l| {
-            l.chars()
-                .flat_map(|c| c.to_digit(10))
-                .map(|d| d as usize)
-                .collect()
-        })
-        .collect_vec();
-    (solve_puzzle(&grid, false), solve_puzzle(&grid, true))
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed<'a> = Vec<Vec<usize>>;
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+            .trim()
+            .lines()
+            .map(|l| {
+                l.chars()
+                    .flat_map(|c| c.to_digit(10))
+                    .map(|d| d as usize)
+                    .collect()
+            })
+            .collect_vec()
+    }
+
+    fn part1(grid: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_puzzle(grid, false).into()
+    }
+
+    fn part2(grid: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_puzzle(grid, true).into()
+    }
 }
@@ -1,3 +1,5 @@
+use super::{Answer, Solution};
+use crate::days::helpers::polygon_area;
 use itertools::Itertools;
 
 fn parse(input: &str) -> Vec<(char, isize, String)> {
@@ -40,7 +42,7 @@ fn parse_poly(instructions: &[(char, isize)]) -> Vec<(isize, isize)> {
         .collect()
 }
 
-fn parse_hexa(hexa: &String) -> (char, isize) {
+fn parse_hexa(hexa: &str) -> (char, isize) {
     let (hex_num, d) = hexa.split_at(5);
     let d = match d {
         "0" => 'R',
@@ -52,21 +54,6 @@ fn parse_hexa(hexa: &String) -> (char, isize) {
     (d, isize::from_str_radix(hex_num, 16).unwrap())
 }
 
-fn polygon_area(polygon: &[(isize, isize)], perimeter: isize) -> isize {
-    polygon[..polygon.len() - 1]
-        .iter()
-        .zip(polygon[1..].iter())
-        .fold(0, |mut acc, (p1, p2)| {
-            acc += (p1.0 + p2.0) * (p1.1 - p2.1); // trapezoid/shoelace formula
-            acc
-        })
-        .abs()
-        / 2
-        // pick's theorem
-        + perimeter / 2
-        + 1
-}
-
 fn solve_1(input: &[(char, isize, String)]) -> usize {
     let poly = parse_poly(&input.iter().map(|(c, d, _)| (*c, *d)).collect_vec());
     polygon_area(&poly, input.iter().map(|(_, d, _)| d).sum()) as usize
@@ -78,7 +65,21 @@ fn solve_2(input: &[(char, isize, String)]) -> usize {
     polygon_area(&poly, instructions.iter().map(|(_, d)| d).sum()) as usize
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    let input = parse(input);
-    (solve_1(&input), solve_2(&input))
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed<'a> = Vec<(char, isize, String)>;
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_1(input).into()
+    }
+
+    fn part2(input: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_2(input).into()
+    }
 }
@@ -1,9 +1,10 @@
+use super::{Answer, Solution};
 use ahash::AHashMap;
 use itertools::Itertools;
 use std::cmp::Ordering;
 
 #[derive(Debug)]
-struct Rule {
+pub struct Rule {
     cmp_tuple: Option<(usize, usize, Ordering)>,
     destination: String,
 }
@@ -124,13 +125,26 @@ fn solve_1(rules: &AHashMap<String, Vec<Rule>>, xmas: &[Vec<usize>]) -> usize {
 }
 
 fn solve_2(rules: &AHashMap<String, Vec<Rule>>) -> usize {
-    let mut xmas = std::iter::repeat(1..=4000).take(4).collect_vec();
+    let mut xmas = std::iter::repeat_n(1..=4000, 4).collect_vec();
     solve_ranges(&mut xmas, rules, &String::from("in"))
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    let (rules, xmas) = input.trim().split_once("\n\n").unwrap();
-    let rules = parse_rules(rules);
-    let xmas = parse_xmas(xmas);
-    (solve_1(&rules, &xmas), solve_2(&rules))
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed<'a> = (AHashMap<String, Vec<Rule>>, Vec<Vec<usize>>);
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        let (rules, xmas) = input.trim().split_once("\n\n").unwrap();
+        (parse_rules(rules), parse_xmas(xmas))
+    }
+
+    fn part1((rules, xmas): &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_1(rules, xmas).into()
+    }
+
+    fn part2((rules, _): &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_2(rules).into()
+    }
 }
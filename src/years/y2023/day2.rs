@@ -1,7 +1,8 @@
+use super::{Answer, Solution};
 use itertools::Itertools;
 use std::collections::HashMap;
 
-struct Game {
+pub struct Game {
     id: usize,
     draws: HashMap<String, usize>,
 }
@@ -48,7 +49,7 @@ impl Game {
     }
 }
 
-fn solve_1(input: &str) -> usize {
+fn solve_1(games: &[Game]) -> usize {
     let bag: HashMap<_, _> = vec![
         ("red".to_string(), 12),
         ("green".to_string(), 13),
@@ -56,17 +57,31 @@ fn solve_1(input: &str) -> usize {
     ]
     .into_iter()
     .collect();
-    input
-        .lines()
-        .map(Game::new)
+    games
+        .iter()
         .filter(|g| g.is_valid(&bag))
         .fold(0, |acc, x| acc + x.id)
 }
 
-fn solve_2(input: &str) -> usize {
-    input.lines().map(Game::new).map(|g| g.power()).sum()
+fn solve_2(games: &[Game]) -> usize {
+    games.iter().map(|g| g.power()).sum()
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    (solve_1(input), solve_2(input))
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed<'a> = Vec<Game>;
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.lines().map(Game::new).collect()
+    }
+
+    fn part1(games: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_1(games).into()
+    }
+
+    fn part2(games: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_2(games).into()
+    }
 }
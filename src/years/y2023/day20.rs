@@ -1,9 +1,10 @@
+use super::{Answer, Solution};
 use ahash::AHashMap;
 use itertools::Itertools;
 use std::collections::VecDeque;
 
 #[derive(Debug, Clone)]
-struct Module {
+pub struct Module {
     dst: Vec<String>,
     t: ModuleType,
 }
@@ -50,8 +51,8 @@ impl Module {
 fn parse(input: &str) -> AHashMap<String, Module> {
     let mut v = input.trim().lines().map(Module::new).collect_vec();
     let v_clone = v.clone();
-    v.iter_mut().for_each(|(k, m)| match m.t {
-        ModuleType::Conjunction(ref mut mem) => {
+    v.iter_mut().for_each(|(k, m)| {
+        if let ModuleType::Conjunction(ref mut mem) = m.t {
             *mem = v_clone
                 .iter()
                 .flat_map(|(ik, im)| match im.dst.contains(&k.to_string()) {
@@ -60,7 +61,6 @@ fn parse(input: &str) -> AHashMap<String, Module> {
                 })
                 .collect()
         }
-        _ => {}
     });
     v.into_iter().collect()
 }
@@ -114,8 +114,8 @@ fn press_button(
     (high, low, conj_triggered)
 }
 
-fn solve_1(input: &str) -> usize {
-    let mut modules = parse(input);
+fn solve_1(modules: &AHashMap<String, Module>) -> usize {
+    let mut modules = modules.clone();
     let (high, low, _) = (0..1000)
         .map(|_| press_button(&mut modules, &String::from("")))
         .reduce(|acc, x| (acc.0 + x.0, acc.1 + x.1, acc.2))
@@ -123,8 +123,8 @@ fn solve_1(input: &str) -> usize {
     high * low
 }
 
-fn solve_2(input: &str) -> usize {
-    let mut modules = parse(input);
+fn solve_2(modules: &AHashMap<String, Module>) -> usize {
+    let mut modules = modules.clone();
     let mut button_presses: usize = 0;
     let modules_clone = modules.clone();
     let (rx_ckey, _) = modules_clone
@@ -149,6 +149,21 @@ fn solve_2(input: &str) -> usize {
     cycles.into_iter().reduce(num::integer::lcm).unwrap() as usize
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    (solve_1(input), solve_2(input))
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed<'a> = AHashMap<String, Module>;
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+
+    fn part1(modules: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_1(modules).into()
+    }
+
+    fn part2(modules: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_2(modules).into()
+    }
 }
@@ -1,3 +1,6 @@
+use super::{Answer, Solution};
+use crate::days::helpers::parse_grid;
+use crate::days::params::params;
 use ahash::AHashSet;
 use itertools::Itertools;
 use num::traits::Euclid;
@@ -60,21 +63,40 @@ fn solve_puzzle(grid: &[Vec<char>], start: (isize, isize), steps_exact: usize) -
     count
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    let grid = input
-        .trim()
-        .lines()
-        .map(|l| l.chars().collect_vec())
-        .collect_vec();
-    let start = grid
-        .iter()
-        .enumerate()
-        .find_map(|(r, l)| {
-            l.iter().position(|ch| ch == &'S').map(|c| (r as isize, c as isize))
-        })
-        .unwrap();
-    (
-        solve_puzzle(&grid, start, 64),
-        solve_puzzle(&grid, start, 26501365),
-    )
+params! {
+    pub struct Day21Params {
+        /// Steps of part 1, the example takes 6
+        steps1: usize = 64,
+        /// Steps of part 2
+        steps2: usize = 26501365,
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Parsed<'a> = (Vec<Vec<char>>, (isize, isize));
+    type Params = Day21Params;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        let grid = parse_grid(input);
+        let start = grid
+            .iter()
+            .enumerate()
+            .find_map(|(r, l)| {
+                l.iter()
+                    .position(|ch| ch == &'S')
+                    .map(|c| (r as isize, c as isize))
+            })
+            .unwrap();
+        (grid, start)
+    }
+
+    fn part1((grid, start): &Self::Parsed<'_>, params: &Self::Params) -> Answer {
+        solve_puzzle(grid, *start, params.steps1).into()
+    }
+
+    fn part2((grid, start): &Self::Parsed<'_>, params: &Self::Params) -> Answer {
+        solve_puzzle(grid, *start, params.steps2).into()
+    }
 }
@@ -1,7 +1,10 @@
+use super::{Answer, Solution};
 use ahash::AHashSet;
 use itertools::Itertools;
 
-fn parse(input: &str) -> Vec<Vec<(isize, isize, isize)>> {
+type Brick = Vec<(isize, isize, isize)>;
+
+fn parse(input: &str) -> Vec<Brick> {
     input
         .trim()
         .lines()
@@ -24,17 +27,17 @@ fn parse(input: &str) -> Vec<Vec<(isize, isize, isize)>> {
         .collect()
 }
 
-fn fall(snapshot: &Vec<Vec<(isize, isize, isize)>>) -> (Vec<Vec<(isize, isize, isize)>>, usize) {
+fn fall(snapshot: &[Brick]) -> (Vec<Brick>, usize) {
     let mut all_bricks: AHashSet<(isize, isize, isize)> =
         snapshot.iter().flatten().copied().collect();
-    let mut snapshot = snapshot.clone();
+    let mut snapshot = snapshot.to_vec();
     let mut count = AHashSet::new();
     loop {
         let moved = snapshot.iter_mut().enumerate().any(|(i, bl)| {
-            if bl.iter().any(|b| b.2 == 1) {
-                false
-            } else if bl.iter().any(|b| {
-                all_bricks.contains(&(b.0, b.1, b.2 - 1)) && !bl.contains(&(b.0, b.1, b.2 - 1))
+            if bl.iter().any(|b| {
+                b.2 == 1
+                    || all_bricks.contains(&(b.0, b.1, b.2 - 1))
+                        && !bl.contains(&(b.0, b.1, b.2 - 1))
             }) {
                 false
             } else {
@@ -54,12 +57,12 @@ fn fall(snapshot: &Vec<Vec<(isize, isize, isize)>>) -> (Vec<Vec<(isize, isize, i
     (snapshot, count.len())
 }
 
-fn disintegrate(bricks: &Vec<Vec<(isize, isize, isize)>>) -> usize {
+fn disintegrate(bricks: &[Brick]) -> usize {
     bricks
         .iter()
         .enumerate()
         .filter(|(i, _)| {
-            let mut removed = bricks.clone();
+            let mut removed = bricks.to_vec();
             removed.remove(*i);
             let (after, _) = fall(&removed);
             after == removed
@@ -67,12 +70,12 @@ fn disintegrate(bricks: &Vec<Vec<(isize, isize, isize)>>) -> usize {
         .count()
 }
 
-fn chain_reaction(bricks: &Vec<Vec<(isize, isize, isize)>>) -> usize {
+fn chain_reaction(bricks: &[Brick]) -> usize {
     bricks
         .iter()
         .enumerate()
         .map(|(i, _)| {
-            let mut removed = bricks.clone();
+            let mut removed = bricks.to_vec();
             removed.remove(i);
             let (_, moved) = fall(&removed);
             moved
@@ -80,8 +83,22 @@ fn chain_reaction(bricks: &Vec<Vec<(isize, isize, isize)>>) -> usize {
         .sum()
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    let snapshot = parse(input);
-    let (bricks, _) = fall(&snapshot);
-    (disintegrate(&bricks), chain_reaction(&bricks))
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Parsed<'a> = Vec<Brick>;
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        let (bricks, _) = fall(&parse(input));
+        bricks
+    }
+
+    fn part1(bricks: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        disintegrate(bricks).into()
+    }
+
+    fn part2(bricks: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        chain_reaction(bricks).into()
+    }
 }
@@ -0,0 +1,137 @@
+use super::{Answer, Solution};
+use crate::days::helpers::parse_grid;
+use ahash::{AHashMap, AHashSet};
+use itertools::Itertools;
+
+fn neighbours(grid: &[Vec<char>], loc: (usize, usize), slopes: bool) -> Vec<(usize, usize)> {
+    let dirs = match (slopes, grid[loc.0][loc.1]) {
+        (true, '^') => vec![(-1, 0)],
+        (true, 'v') => vec![(1, 0)],
+        (true, '<') => vec![(0, -1)],
+        (true, '>') => vec![(0, 1)],
+        _ => vec![(-1, 0), (1, 0), (0, -1), (0, 1)],
+    };
+    dirs.into_iter()
+        .map(|(dr, dc)| (loc.0 as isize + dr, loc.1 as isize + dc))
+        .filter(|(r, c)| {
+            (0..grid.len() as isize).contains(r) && (0..grid[0].len() as isize).contains(c)
+        })
+        .map(|(r, c)| (r as usize, c as usize))
+        .filter(|&(r, c)| grid[r][c] != '#')
+        .collect()
+}
+
+fn junctions(
+    grid: &[Vec<char>],
+    start: (usize, usize),
+    end: (usize, usize),
+) -> Vec<(usize, usize)> {
+    let mut junctions = vec![start, end];
+    junctions.extend(
+        grid.iter()
+            .enumerate()
+            .flat_map(|(r, l)| l.iter().enumerate().map(move |(c, ch)| (r, c, ch)))
+            .filter(|(_, _, ch)| **ch != '#')
+            .map(|(r, c, _)| (r, c))
+            .filter(|&loc| neighbours(grid, loc, false).len() > 2),
+    );
+    junctions
+}
+
+// compress the maze into a graph of junctions where each edge is the length of the
+// corridor between them, this keeps the search space of the longest path tiny.
+fn compress(
+    grid: &[Vec<char>],
+    junctions: &[(usize, usize)],
+    slopes: bool,
+) -> Vec<Vec<(usize, usize)>> {
+    let index: AHashMap<(usize, usize), usize> = junctions
+        .iter()
+        .enumerate()
+        .map(|(i, &loc)| (loc, i))
+        .collect();
+    junctions
+        .iter()
+        .map(|&from| {
+            let mut edges = Vec::new();
+            let mut explored = AHashSet::new();
+            let mut frontier = vec![(from, 0)];
+            explored.insert(from);
+            while let Some((loc, g)) = frontier.pop() {
+                if g > 0 {
+                    if let Some(&to) = index.get(&loc) {
+                        edges.push((to, g));
+                        continue;
+                    }
+                }
+                for n in neighbours(grid, loc, slopes) {
+                    // junctions can be reached through several corridors
+                    if index.contains_key(&n) && n != from || explored.insert(n) {
+                        frontier.push((n, g + 1));
+                    }
+                }
+            }
+            edges
+        })
+        .collect()
+}
+
+fn longest_path(
+    graph: &[Vec<(usize, usize)>],
+    node: usize,
+    goal: usize,
+    visited: u64,
+) -> Option<usize> {
+    if node == goal {
+        return Some(0);
+    }
+    graph[node]
+        .iter()
+        .filter(|(n, _)| visited & (1 << n) == 0)
+        .flat_map(|&(n, d)| longest_path(graph, n, goal, visited | (1 << n)).map(|l| l + d))
+        .max()
+}
+
+fn solve_puzzle(grid: &[Vec<char>], slopes: bool) -> usize {
+    let start = (0, grid[0].iter().position(|&ch| ch == '.').unwrap());
+    let end = (
+        grid.len() - 1,
+        grid[grid.len() - 1]
+            .iter()
+            .position(|&ch| ch == '.')
+            .unwrap(),
+    );
+    let junctions = junctions(grid, start, end);
+    assert!(junctions.len() <= 64, "too many junctions for the bitmask");
+    let graph = compress(grid, &junctions, slopes);
+    // the exit is only reachable through a single junction, if we walk past it we can
+    // never reach the exit, so we treat that junction as the goal instead.
+    let (goal, to_end) = match graph
+        .iter()
+        .positions(|edges| edges.iter().any(|(n, _)| *n == 1))
+        .collect_vec()[..]
+    {
+        [last] if last != 0 => (last, graph[last].iter().find(|(n, _)| *n == 1).unwrap().1),
+        _ => (1, 0),
+    };
+    longest_path(&graph, 0, goal, 1).unwrap() + to_end
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Parsed<'a> = Vec<Vec<char>>;
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_grid(input)
+    }
+
+    fn part1(grid: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_puzzle(grid, true).into()
+    }
+
+    fn part2(grid: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_puzzle(grid, false).into()
+    }
+}
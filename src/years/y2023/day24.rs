@@ -1,3 +1,5 @@
+use super::{Answer, Solution};
+use crate::days::params::params;
 use itertools::Itertools;
 use std::ops::RangeInclusive;
 use z3::{
@@ -25,7 +27,7 @@ impl Vec3 {
 }
 
 #[derive(Debug)]
-struct Hailstone {
+pub struct Hailstone {
     pos: Vec3,
     vel: Vec3,
 }
@@ -122,7 +124,7 @@ fn solve_1(hailstones: &[Hailstone], range: RangeInclusive<f64>) -> usize {
         .count()
 }
 
-fn solve_2(hailstones: &[Hailstone]) -> usize {
+fn solve_2(hailstones: &[Hailstone]) -> i64 {
     // we need to find t s.t.
     // x_rock + t * vx_rock = x_hail + t * vx_hail
     // y_rock + t * vy_rock = y_hail + t * vy_hail
@@ -169,15 +171,34 @@ fn solve_2(hailstones: &[Hailstone]) -> usize {
                 .and_then(|z| z.as_i64())
                 .unwrap(),
         );
-        return (x + y + z) as usize;
+        return x + y + z;
     }
     panic!()
 }
-pub fn solve(input: &str) -> (usize, usize) {
-    let hailstones = parse(input);
-    // (solve_1(&hailstones, 7.0..=27.0), solve_2(&hailstones)) -- for test input
-    (
-        solve_1(&hailstones, 200000000000000.0..=400000000000000.0),
-        solve_2(&hailstones),
-    )
+params! {
+    pub struct Day24Params {
+        /// Lower bound of the test area of part 1 on both axes, the example takes 7
+        min: f64 = 200000000000000.0,
+        /// Upper bound of the test area of part 1 on both axes, the example takes 27
+        max: f64 = 400000000000000.0,
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Parsed<'a> = Vec<Hailstone>;
+    type Params = Day24Params;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+
+    fn part1(hailstones: &Self::Parsed<'_>, params: &Self::Params) -> Answer {
+        solve_1(hailstones, params.min..=params.max).into()
+    }
+
+    fn part2(hailstones: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_2(hailstones).into()
+    }
 }
@@ -1,3 +1,4 @@
+use super::{Answer, Solution};
 use ahash::HashMap;
 use itertools::Itertools;
 use regex::Regex;
@@ -6,7 +7,7 @@ use rustworkx_core::{
     petgraph::{graph::UnGraph, Graph, Undirected},
 };
 
-pub fn solve(input: &str) -> (usize, usize) {
+fn solve_1(input: &str) -> usize {
     let wre = Regex::new(r"(\w+)").unwrap();
     let edge_indexes: HashMap<&str, usize> = input
         .trim()
@@ -30,5 +31,25 @@ pub fn solve(input: &str) -> (usize, usize) {
         .unwrap()
         .unwrap();
 
-    (partition.len() * (graph.node_count() - partition.len()), 0)
+    partition.len() * (graph.node_count() - partition.len())
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Parsed<'a> = &'a str;
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_1(input).into()
+    }
+
+    // day 25 only has one puzzle
+    fn part2(_: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        Answer::None
+    }
 }
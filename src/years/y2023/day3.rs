@@ -1,7 +1,9 @@
-use itertools::{iproduct, Itertools};
+use super::{Answer, Solution};
+use crate::days::helpers::parse_grid;
+use itertools::iproduct;
 use std::collections::{HashMap, HashSet};
 
-fn adjacent_symbols(grid: &Vec<Vec<char>>, x: &usize, y: &usize) -> HashSet<(usize, usize, char)> {
+fn adjacent_symbols(grid: &[Vec<char>], x: &usize, y: &usize) -> HashSet<(usize, usize, char)> {
     iproduct!(-1..=1, -1..=1)
         .filter(|&d| d != (0, 0))
         .filter_map(|(dx, dy)| {
@@ -21,8 +23,8 @@ fn adjacent_symbols(grid: &Vec<Vec<char>>, x: &usize, y: &usize) -> HashSet<(usi
         .collect()
 }
 
-fn solve_d(input: &str) -> (usize, usize) {
-    let grid = input.lines().map(|l| l.chars().collect_vec()).collect_vec();
+fn scan(input: &str) -> (usize, HashMap<(usize, usize), Vec<usize>>) {
+    let grid = parse_grid(input);
     let mut res = 0;
     let mut nums = HashMap::<(usize, usize), Vec<usize>>::new();
     for (i, l) in grid.iter().enumerate() {
@@ -69,16 +71,34 @@ fn solve_d(input: &str) -> (usize, usize) {
             }
         }
     }
+    (res, nums)
+}
+
+fn gear_ratios(nums: &HashMap<(usize, usize), Vec<usize>>) -> usize {
     let mut s2 = 0;
-    for (_, v) in nums {
+    for v in nums.values() {
         if v.len() == 2 {
             s2 += v[0] * v[1]
         }
     }
-    (res, s2)
+    s2
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    let (s1, s2) = solve_d(input);
-    (s1, s2)
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed<'a> = (usize, HashMap<(usize, usize), Vec<usize>>);
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        scan(input)
+    }
+
+    fn part1((s1, _): &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        (*s1).into()
+    }
+
+    fn part2((_, nums): &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        gear_ratios(nums).into()
+    }
 }
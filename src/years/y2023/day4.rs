@@ -1,7 +1,8 @@
+use super::{Answer, Solution};
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 
-struct Card {
+pub struct Card {
     id: usize,
     win_nums: HashSet<i64>,
     drawn_nums: HashSet<i64>,
@@ -19,8 +20,10 @@ impl Card {
     }
 
     fn score(&self) -> usize {
-        let base: usize = 2;
-        base.pow(self.matching_nums().saturating_sub(1).try_into().unwrap())
+        match self.matching_nums() {
+            0 => 0,
+            n => 1 << (n - 1),
+        }
     }
 
     fn matching_nums(&self) -> usize {
@@ -44,12 +47,26 @@ fn solve_2(cards: &[Card]) -> usize {
     counts.values().sum()
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    let cards = input
-        .trim()
-        .lines()
-        .enumerate()
-        .map(|(i, l)| Card::new(l, i))
-        .collect_vec();
-    (solve_1(&cards), solve_2(&cards))
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed<'a> = Vec<Card>;
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+            .trim()
+            .lines()
+            .enumerate()
+            .map(|(i, l)| Card::new(l, i))
+            .collect_vec()
+    }
+
+    fn part1(cards: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_1(cards).into()
+    }
+
+    fn part2(cards: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_2(cards).into()
+    }
 }
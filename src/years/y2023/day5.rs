@@ -1,3 +1,4 @@
+use super::{Answer, Solution};
 use itertools::Itertools;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use std::{
@@ -7,7 +8,7 @@ use std::{
 };
 
 #[derive(Debug)]
-struct GardenMap {
+pub struct GardenMap {
     src: String,
     dst: String,
     ranges: Vec<(Range<usize>, isize)>,
@@ -46,7 +47,7 @@ impl GardenMap {
     }
 }
 
-fn solve_1(seeds: &Vec<usize>, garden_lookups: &HashMap<String, GardenMap>) -> usize {
+fn solve_1(seeds: &[usize], garden_lookups: &HashMap<String, GardenMap>) -> usize {
     seeds
         .iter()
         .map(|&s| {
@@ -61,7 +62,7 @@ fn solve_1(seeds: &Vec<usize>, garden_lookups: &HashMap<String, GardenMap>) -> u
         .unwrap()
 }
 
-fn solve_2(seeds: &Vec<Range<usize>>, garden_lookups: &HashMap<String, GardenMap>) -> usize {
+fn solve_2(seeds: &[Range<usize>], garden_lookups: &HashMap<String, GardenMap>) -> usize {
     let total = seeds.len();
     let c = AtomicU8::new(0);
     let c_min = AtomicUsize::new(usize::MAX);
@@ -74,7 +75,7 @@ fn solve_2(seeds: &Vec<Range<usize>>, garden_lookups: &HashMap<String, GardenMap
             c_min.fetch_min(m, std::sync::atomic::Ordering::Relaxed);
             m
         })
-        .inspect(|_| println!("Prog: {c:?}/{total} - current_min: {c_min:?}"))
+        .inspect(|_| eprintln!("Prog: {c:?}/{total} - current_min: {c_min:?}"))
         .min()
         .unwrap()
 }
@@ -116,7 +117,22 @@ fn seed_ranges(input: &str) -> Vec<Range<usize>> {
         .collect_vec()
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    let (seeds, maps) = parse(input);
-    (solve_1(&seeds, &maps), solve_2(&seed_ranges(input), &maps))
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed<'a> = (Vec<usize>, Vec<Range<usize>>, HashMap<String, GardenMap>);
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        let (seeds, maps) = parse(input);
+        (seeds, seed_ranges(input), maps)
+    }
+
+    fn part1((seeds, _, maps): &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_1(seeds, maps).into()
+    }
+
+    fn part2((_, seed_ranges, maps): &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_2(seed_ranges, maps).into()
+    }
 }
@@ -1,3 +1,4 @@
+use super::{Answer, Solution};
 use itertools::Itertools;
 use regex::{self, Regex};
 
@@ -31,9 +32,21 @@ fn parse_input_2(input: &str) -> (usize, usize) {
         .unwrap()
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    (
-        solve_puzzle(parse_input(input)),
-        solve_puzzle(vec![parse_input_2(input)]),
-    )
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed<'a> = (Vec<(usize, usize)>, (usize, usize));
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        (parse_input(input), parse_input_2(input))
+    }
+
+    fn part1((races, _): &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_puzzle(races.clone()).into()
+    }
+
+    fn part2((_, race): &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_puzzle(vec![*race]).into()
+    }
 }
@@ -1,7 +1,8 @@
+use super::{Answer, Solution};
 use itertools::Itertools;
 use std::cmp::Ordering::{Equal, Greater, Less};
 
-#[derive(Eq, Ord)]
+#[derive(Eq)]
 struct Hand {
     cards: Vec<u8>,
     bet: usize,
@@ -84,10 +85,15 @@ impl PartialEq for Hand {
 
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         match self.get_type().cmp(&other.get_type()) {
-            Less => Some(Less),
-            Greater => Some(Greater),
-            Equal => Some(self.seq_highest(other)),
+            Equal => self.seq_highest(other),
+            ordering => ordering,
         }
     }
 }
@@ -116,6 +122,21 @@ fn solve_puzzle(input: &str, joker: bool) -> usize {
         .sum()
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    (solve_puzzle(input, false), solve_puzzle(input, true))
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed<'a> = &'a str;
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_puzzle(input, false).into()
+    }
+
+    fn part2(input: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_puzzle(input, true).into()
+    }
 }
@@ -1,3 +1,4 @@
+use super::{Answer, Solution};
 use itertools::FoldWhile::{Continue, Done};
 use itertools::Itertools;
 use num::integer::lcm;
@@ -47,10 +48,21 @@ fn solve_2(i: &[char], m: &HashMap<&str, (&str, &str)>) -> usize {
         .unwrap()
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    let (instructions, map) = parse(input);
-    (
-        solve_1(&instructions, &map, "AAA", "ZZZ"),
-        solve_2(&instructions, &map),
-    )
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed<'a> = (Vec<char>, HashMap<&'a str, (&'a str, &'a str)>);
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+
+    fn part1((instructions, map): &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_1(instructions, map, "AAA", "ZZZ").into()
+    }
+
+    fn part2((instructions, map): &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_2(instructions, map).into()
+    }
 }
@@ -1,3 +1,4 @@
+use super::{Answer, Solution};
 use itertools::{unfold, Itertools};
 
 fn parse(input: &str) -> Vec<Vec<Vec<i64>>> {
@@ -21,19 +22,33 @@ fn parse(input: &str) -> Vec<Vec<Vec<i64>>> {
         .collect_vec()
 }
 
-fn solve_2(v: &[Vec<Vec<i64>>]) -> usize {
+fn solve_2(v: &[Vec<Vec<i64>>]) -> i64 {
     v.iter()
         .map(|n| n.iter().rev().fold(0, |acc, x| x.first().unwrap() - acc))
-        .sum::<i64>() as usize
+        .sum()
 }
 
-fn solve_1(v: &[Vec<Vec<i64>>]) -> usize {
+fn solve_1(v: &[Vec<Vec<i64>>]) -> i64 {
     v.iter()
         .map(|n| n.iter().rev().fold(0, |acc, x| x.last().unwrap() + acc))
-        .sum::<i64>() as usize
+        .sum()
 }
 
-pub fn solve(input: &str) -> (usize, usize) {
-    let v = parse(input);
-    (solve_1(&v), solve_2(&v))
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Parsed<'a> = Vec<Vec<Vec<i64>>>;
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+
+    fn part1(v: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_1(v).into()
+    }
+
+    fn part2(v: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        solve_2(v).into()
+    }
 }
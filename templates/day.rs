@@ -0,0 +1,20 @@
+use super::{Answer, Solution};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Parsed<'a> = Vec<&'a str>;
+    type Params = ();
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input.lines().collect()
+    }
+
+    fn part1(_lines: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        Answer::None
+    }
+
+    fn part2(_lines: &Self::Parsed<'_>, _: &Self::Params) -> Answer {
+        Answer::None
+    }
+}
@@ -0,0 +1,209 @@
+#![allow(dead_code)]
+
+use aoc2023::ExampleStore;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Response {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Response {
+    pub fn new(status: u16, body: &str) -> Self {
+        Self {
+            status,
+            headers: vec![],
+            body: body.to_string(),
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// A tiny HTTP server standing in for adventofcode.com, every request is answered by `handler`.
+pub struct FakeAoc {
+    pub url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl FakeAoc {
+    pub fn start(handler: impl Fn(&Request) -> Response + Send + 'static) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { continue };
+                let Some(request) = read_request(&mut stream) else {
+                    continue;
+                };
+                let response = handler(&request);
+                recorded.lock().unwrap().push(request);
+                let mut head = format!(
+                    "HTTP/1.1 {} Fake\r\nContent-Length: {}\r\nConnection: close\r\n",
+                    response.status,
+                    response.body.len()
+                );
+                for (k, v) in response.headers.iter() {
+                    head.push_str(&format!("{k}: {v}\r\n"));
+                }
+                head.push_str("\r\n");
+                let _ = stream.write_all(head.as_bytes());
+                let _ = stream.write_all(response.body.as_bytes());
+            }
+        });
+        Self { url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn read_request(stream: &mut std::net::TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let path = parts.next()?.to_string();
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        let (k, v) = line.split_once(':')?;
+        headers.push((k.trim().to_string(), v.trim().to_string()));
+    }
+    let length = headers
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, v)| v.parse().ok())
+        .unwrap_or(0);
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body).ok()?;
+    Some(Request {
+        method,
+        path,
+        headers,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+/// A fresh, empty directory under the system temp dir.
+pub fn temp_dir(name: &str) -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let dir = std::env::temp_dir().join(format!(
+        "aoc-{name}-{}-{}",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// A recorded page from `tests/fixtures`.
+pub fn fixture(path: &str) -> String {
+    std::fs::read_to_string(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(path),
+    )
+    .unwrap()
+}
+
+/// The checked-in main example of a day, `examples/{year}/{day}.ex`.
+pub fn example(year: u32, day: u32) -> String {
+    std::fs::read_to_string(
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("examples/{year}/{day}.ex")),
+    )
+    .unwrap()
+}
+
+/// Puts the main examples of `days` in the input cache `dir` as their real inputs.
+pub fn example_inputs(dir: &Path, year: u32, days: &[u32]) {
+    std::fs::create_dir_all(dir).unwrap();
+    for &day in days {
+        std::fs::write(dir.join(format!("{year}-{day}.in")), example(year, day)).unwrap();
+    }
+}
+
+/// Puts the main examples of `days` with their checked-in answers in the example store of the
+/// project `dir`, i.e. in `examples/{year}/`.
+pub fn example_store(dir: &Path, year: u32, days: &[u32]) {
+    let checked_in = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("examples/{year}"));
+    let checked_in = ExampleStore::open(checked_in).unwrap();
+    let mut store = ExampleStore::open(dir.join(format!("examples/{year}"))).unwrap();
+    for &day in days {
+        let name = format!("{day}.ex");
+        for example in checked_in.examples(day).unwrap() {
+            if example.name == name {
+                store.add(&name, &example.input, example.answers).unwrap();
+            }
+        }
+    }
+    store.save().unwrap();
+}
+
+/// Writes `content` to `path`, creating the directories on the way.
+pub fn write(path: &Path, content: &str) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(path, content).unwrap();
+}
+
+/// The runner binary, isolated from the developer's session, inputs, answers, `aoc.toml` and network.
+/// Answers, examples and the user config are kept in `answers/`, `examples/` and `config/` inside
+/// `input_dir`.
+pub fn aoc(input_dir: &Path, base_url: &str) -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_aoc2023"));
+    cmd.env("AOC_INPUT_DIR", input_dir)
+        .env("AOC_ANSWER_DIR", input_dir.join("answers"))
+        .env("AOC_EXAMPLE_DIR", input_dir.join("examples"))
+        .env("AOC_BASE_URL", base_url)
+        .env("AOC_CONFIG_DIR", input_dir.join("config"))
+        .env("AOC_CONFIG", "")
+        .env("AOC_SESSION", "test-session")
+        .env_remove("AOC_PROFILE")
+        .env_remove("AOC_OFFLINE");
+    cmd
+}
+
+pub fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+pub fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
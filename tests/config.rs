@@ -0,0 +1,160 @@
+mod common;
+
+use common::{aoc, example_inputs, example_store, stderr, stdout, temp_dir, FakeAoc, Response};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// A project with `aoc.toml` and inputs in `data/inputs` as the config says.
+fn project(config: &str) -> PathBuf {
+    let dir = temp_dir("config");
+    example_inputs(&dir.join("data/inputs"), 2020, &[1]);
+    example_inputs(&dir.join("data/inputs"), 2023, &[1, 2]);
+    std::fs::write(dir.join("data/aoc.toml"), config).unwrap();
+    dir
+}
+
+/// The runner with the project's config and without the directories of the test harness.
+fn configured(dir: &Path, base_url: &str) -> Command {
+    let mut cmd = aoc(dir, base_url);
+    cmd.env("AOC_CONFIG", dir.join("data/aoc.toml"))
+        .env_remove("AOC_INPUT_DIR")
+        .env_remove("AOC_ANSWER_DIR");
+    cmd
+}
+
+fn run(cmd: &mut Command) -> String {
+    let output = cmd.output().unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    stdout(&output)
+}
+
+#[test]
+fn config_sets_the_defaults() {
+    let dir = project(
+        "year = 2020\n\
+         input_dir = \"inputs\"\n\
+         answer_dir = \"answers\"\n",
+    );
+    assert_eq!(
+        run(configured(&dir, "").args(["run", "-d", "1"])),
+        "Day1: (514579, 241861950)\n"
+    );
+    run(configured(&dir, "").args(["verify", "-d", "1", "--record"]));
+    assert!(dir.join("data/answers/2020.toml").exists());
+}
+
+#[test]
+fn flags_override_the_config() {
+    let dir = project(
+        "year = 2020\n\
+         input_dir = \"inputs\"\n\
+         format = \"csv\"\n\
+         jobs = 2\n",
+    );
+    let csv = run(configured(&dir, "").args(["run", "-d", "1"]));
+    assert!(
+        csv.starts_with("year,day,part,answer,") && csv.contains("\n2020,1,1,514579,"),
+        "{csv}"
+    );
+    assert_eq!(
+        run(configured(&dir, "").args(["run", "-y", "2023", "-d", "1", "--format", "table"])),
+        "Day1: (142, 142)\n"
+    );
+    // the environment beats the config too
+    let other = temp_dir("config-env");
+    std::fs::write(other.join("2020-1.in"), "1721\n299\n").unwrap();
+    assert_eq!(
+        run(configured(&dir, "")
+            .env("AOC_INPUT_DIR", &other)
+            .args(["run", "-d", "1", "-p", "1", "--format", "table"])),
+        "Day1: 514579\n"
+    );
+}
+
+#[test]
+fn config_timeout() {
+    let dir = project("input_dir = \"inputs\"\ntimeout = 0.2\n");
+    std::fs::write(
+        dir.join("data/inputs/2023-20.in"),
+        "broadcaster -> a\n%a -> out\n&inv -> con\n&con -> rx\n",
+    )
+    .unwrap();
+    assert_eq!(
+        run(configured(&dir, "").args(["run", "-d", "20"])),
+        "Day20: (1250000, TIMEOUT)\n"
+    );
+}
+
+#[test]
+fn config_user_agent() {
+    let server = FakeAoc::start(|_| Response::new(200, "1 2 3\n"));
+    let dir = project("input_dir = \"inputs\"\nuser_agent = \"aoc-runner by me@example.com\"\n");
+    let output = configured(&dir, &server.url)
+        .args(["fetch", "-d", "4"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        server.requests()[0].header("user-agent"),
+        Some("aoc-runner by me@example.com")
+    );
+    assert!(dir.join("data/inputs/2023-4.in").exists());
+}
+
+#[test]
+fn invalid_config_is_an_error() {
+    let dir = project("yaer = 2020\n");
+    let output = configured(&dir, "").args(["run"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("failed to parse") && stderr(&output).contains("yaer"),
+        "{}",
+        stderr(&output)
+    );
+
+    let dir = project("format = \"xml\"\n");
+    let output = configured(&dir, "").args(["run"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("xml"), "{}", stderr(&output));
+
+    let dir = project("user_agent = \"me\\nand you\"\n");
+    let output = configured(&dir, "").args(["run"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("user_agent \"me\\nand you\" can't be sent as a header"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn today_uses_the_config() {
+    let dir = project("input_dir = \"inputs\"\nformat = \"csv\"\n");
+    let csv = run(configured(&dir, "").args(["today", "--puzzle", "2023-2"]));
+    assert!(
+        csv.starts_with("year,day,part,answer,") && csv.contains("\n2023,2,1,8,"),
+        "{csv}"
+    );
+}
+
+#[test]
+fn today_keeps_json_on_stdout_clean() {
+    let dir = project("input_dir = \"inputs\"\nformat = \"json\"\n");
+    example_store(&dir, 2023, &[2]);
+    let output = configured(&dir, "")
+        .args(["today", "--puzzle", "2023-2"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    let records: Vec<serde_json::Value> = serde_json::from_str(&stdout(&output)).unwrap();
+    let answers = records
+        .iter()
+        .map(|r| r["answer"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(answers, ["8", "2286"]);
+    assert!(
+        stderr(&output).starts_with("2023 day 2\nDay2 part 1 2.ex: PASS 8\n"),
+        "{}",
+        stderr(&output)
+    );
+}
@@ -0,0 +1,162 @@
+mod common;
+
+use common::{aoc, stderr, temp_dir, FakeAoc, Response};
+
+#[test]
+fn downloads_and_caches_input() {
+    let server = FakeAoc::start(|_| Response::new(200, "1 2 3\n"));
+    let dir = temp_dir("download-ok");
+    let output = aoc(&dir, &server.url)
+        .args(["fetch", "-y", "2023", "-d", "4"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        std::fs::read_to_string(dir.join("2023-4.in")).unwrap(),
+        "1 2 3\n"
+    );
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2023/day/4/input");
+    assert_eq!(requests[0].header("cookie"), Some("session=test-session"));
+}
+
+#[test]
+fn cached_input_is_not_downloaded_again() {
+    let server = FakeAoc::start(|_| Response::new(200, "fresh\n"));
+    let dir = temp_dir("download-cached");
+    std::fs::write(dir.join("2023-4.in"), "cached\n").unwrap();
+    let output = aoc(&dir, &server.url)
+        .args(["fetch", "-d", "4"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(server.requests().is_empty());
+    assert_eq!(
+        std::fs::read_to_string(dir.join("2023-4.in")).unwrap(),
+        "cached\n"
+    );
+}
+
+#[test]
+fn offline_mode_never_touches_the_network() {
+    let server = FakeAoc::start(|_| Response::new(200, "1 2 3\n"));
+    let dir = temp_dir("download-offline");
+    let output = aoc(&dir, &server.url)
+        .args(["--offline", "fetch", "-d", "3-5"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("day 3, 4, 5"),
+        "{}",
+        stderr(&output)
+    );
+    assert!(server.requests().is_empty());
+}
+
+fn assert_failed_without_cache(response: Response, expected: &str) {
+    let server = FakeAoc::start(move |_| response.clone());
+    let dir = temp_dir("download-fail");
+    let output = aoc(&dir, &server.url)
+        .args(["fetch", "-d", "4"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains(expected), "{}", stderr(&output));
+    assert!(!dir.join("2023-4.in").exists());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn puzzle_not_unlocked() {
+    assert_failed_without_cache(
+        Response::new(
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks! \
+             The calendar countdown is synchronized with the server time; \
+             the link will be enabled on the calendar the instant this puzzle becomes available.\n",
+        ),
+        "not unlocked yet",
+    );
+}
+
+#[test]
+fn bad_session_cookie() {
+    assert_failed_without_cache(
+        Response::new(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+        ),
+        "session cookie is expired or invalid",
+    );
+}
+
+#[test]
+fn redirect_is_not_followed() {
+    assert_failed_without_cache(
+        Response::new(302, "").header("Location", "/"),
+        "session cookie is expired or invalid",
+    );
+}
+
+#[test]
+fn server_error() {
+    assert_failed_without_cache(
+        Response::new(500, "Internal Server Error"),
+        "unexpected response 500",
+    );
+}
+
+#[test]
+fn rate_limited() {
+    assert_failed_without_cache(Response::new(429, "Too Many Requests"), "rate limited");
+}
+
+const LOGIN_PAGE: &str = "<!DOCTYPE html>\n<html lang=\"en-us\">\n<head>\n\
+    <title>Advent of Code 2023</title>\n</head>\n<body>\n\
+    <p>To play, please identify yourself via one of these services:</p>\n</body>\n</html>\n";
+
+#[test]
+fn login_page_is_not_cached() {
+    assert_failed_without_cache(
+        Response::new(200, LOGIN_PAGE),
+        "session cookie is expired or invalid",
+    );
+}
+
+#[test]
+fn cached_login_page_is_downloaded_again() {
+    let server = FakeAoc::start(|_| Response::new(200, "1 2 3\n"));
+    let dir = temp_dir("download-cached-login");
+    std::fs::write(dir.join("2023-4.in"), LOGIN_PAGE).unwrap();
+    let output = aoc(&dir, &server.url)
+        .args(["fetch", "-d", "4"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(server.requests().len(), 1);
+    assert_eq!(
+        std::fs::read_to_string(dir.join("2023-4.in")).unwrap(),
+        "1 2 3\n"
+    );
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+}
+
+#[test]
+fn network_unavailable() {
+    // nothing listens on a port that was just released
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    drop(listener);
+    let dir = temp_dir("download-no-network");
+    let output = aoc(&dir, &url).args(["fetch", "-d", "4"]).output().unwrap();
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("network unavailable"),
+        "{}",
+        stderr(&output)
+    );
+    assert!(!dir.join("2023-4.in").exists());
+}
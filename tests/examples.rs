@@ -0,0 +1,117 @@
+mod common;
+
+use common::{aoc, stderr, stdout, temp_dir};
+use std::path::Path;
+
+/// Checks a day against the examples checked in under `examples/`.
+fn check_day(year: u32, day: u32) {
+    let dir = temp_dir("examples");
+    let output = aoc(&dir, "http://localhost:0")
+        .env(
+            "AOC_EXAMPLE_DIR",
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("examples"),
+        )
+        .args(["examples", "-y", &year.to_string(), "-d", &day.to_string()])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}{}",
+        stdout(&output),
+        stderr(&output)
+    );
+}
+
+macro_rules! example_tests {
+    ($($module:ident => $year:literal { $($name:ident => $day:literal),* $(,)? })*) => {
+        $(
+            mod $module {
+                $(
+                    #[test]
+                    fn $name() {
+                        super::check_day($year, $day);
+                    }
+                )*
+            }
+        )*
+    };
+}
+
+example_tests! {
+    y2020 => 2020 {
+        day1 => 1,
+    }
+    y2023 => 2023 {
+        day1 => 1, day2 => 2, day3 => 3, day4 => 4, day5 => 5,
+        day6 => 6, day7 => 7, day8 => 8, day9 => 9, day10 => 10,
+        day11 => 11, day12 => 12, day13 => 13, day14 => 14, day15 => 15,
+        day16 => 16, day17 => 17, day18 => 18, day19 => 19, day20 => 20,
+        day21 => 21, day22 => 22, day23 => 23, day24 => 24, day25 => 25,
+    }
+}
+
+#[test]
+fn discovers_every_example_of_a_day() {
+    let dir = temp_dir("examples-discovery");
+    let examples = dir.join("examples/2023");
+    std::fs::create_dir_all(&examples).unwrap();
+    std::fs::write(examples.join("9.ex"), "0 3 6 9 12 15\n").unwrap();
+    std::fs::write(examples.join("9-b.ex"), "1 3 6 10 15 21\n").unwrap();
+    std::fs::write(examples.join("9-c.ex"), "10 13 16 21 30 45\n").unwrap();
+    std::fs::write(examples.join("19.ex"), "not day 9\n").unwrap();
+    std::fs::write(
+        examples.join("answers.toml"),
+        r#"
+["9.ex"]
+part1 = "18"
+part2 = "-3"
+
+["9-b.ex"]
+part2 = "0"
+
+["9-c.ex"]
+part1 = "1"
+"#,
+    )
+    .unwrap();
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["examples", "-d", "9"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "Day9 part 1 9.ex: PASS 18\n\
+         Day9 part 2 9.ex: PASS -3\n\
+         Day9 part 2 9-b.ex: PASS 0\n\
+         Day9 part 1 9-c.ex: FAIL\n  \
+         - expected: 1\n  \
+         + actual:   68\n"
+    );
+    assert!(
+        stderr(&output).contains("1 example answer(s) are wrong"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn run_uses_the_main_example() {
+    let dir = temp_dir("examples-run");
+    let examples = dir.join("examples/2023");
+    std::fs::create_dir_all(&examples).unwrap();
+    std::fs::write(examples.join("9.ex"), "0 3 6 9 12 15\n").unwrap();
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["run", "-d", "9", "-e"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "Day9: (18, -3)\n");
+
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["run", "-d", "10", "-e"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("10.ex"), "{}", stderr(&output));
+}
@@ -0,0 +1,111 @@
+mod common;
+
+use common::{aoc, fixture, stderr, stdout, temp_dir, FakeAoc, Response};
+use std::path::{Path, PathBuf};
+
+fn fixture_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/puzzle")
+        .join(name)
+}
+
+fn extract_page(dir: &Path, day: &str, page: &str) -> std::process::Output {
+    aoc(dir, "http://localhost:0")
+        .args(["extract", "-d", day, "--page"])
+        .arg(fixture_path(page))
+        .output()
+        .unwrap()
+}
+
+fn read(dir: &Path, name: &str) -> String {
+    std::fs::read_to_string(dir.join("examples/2023").join(name)).unwrap()
+}
+
+#[test]
+fn separate_example_per_part() {
+    let dir = temp_dir("extract-day1");
+    let output = extract_page(&dir, "1", "2023-1.html");
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        stdout(&output),
+        "1.ex: part 1 142, part 2 -\n1-2.ex: part 1 -, part 2 281\n"
+    );
+    assert_eq!(
+        read(&dir, "1.ex"),
+        "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n"
+    );
+    assert!(read(&dir, "1-2.ex").starts_with("two1nine\neightwothree\n"));
+    assert_eq!(
+        read(&dir, "answers.toml"),
+        "[\"1-2.ex\"]\npart2 = \"281\"\n\n[\"1.ex\"]\npart1 = \"142\"\n"
+    );
+
+    // the extracted examples are what the example checks run on
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["examples", "-d", "1"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stdout(&output));
+    assert_eq!(stdout(&output).matches("PASS").count(), 2);
+}
+
+#[test]
+fn part_two_reuses_the_example_and_skips_worked_solutions() {
+    let dir = temp_dir("extract-day9");
+    let output = extract_page(&dir, "9", "2023-9.html");
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "9.ex: part 1 114, part 2 2\n");
+    assert_eq!(
+        read(&dir, "9.ex"),
+        "0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n"
+    );
+}
+
+#[test]
+fn downloads_the_puzzle_page() {
+    let page = fixture("puzzle/2023-9-part1.html");
+    let server = FakeAoc::start(move |_| Response::new(200, &page));
+    let dir = temp_dir("extract-download");
+    let output = aoc(&dir, &server.url)
+        .args(["extract", "-d", "9"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "9.ex: part 1 114, part 2 -\n");
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].path, "/2023/day/9");
+    assert_eq!(requests[0].header("cookie"), Some("session=test-session"));
+}
+
+#[test]
+fn existing_examples_are_kept_unless_forced() {
+    let dir = temp_dir("extract-existing");
+    std::fs::create_dir_all(dir.join("examples/2023")).unwrap();
+    std::fs::write(dir.join("examples/2023/9.ex"), "1 2 3\n").unwrap();
+    let output = extract_page(&dir, "9", "2023-9.html");
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("--force"), "{}", stderr(&output));
+    assert_eq!(read(&dir, "9.ex"), "1 2 3\n");
+
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["extract", "-d", "9", "--force", "--page"])
+        .arg(fixture_path("2023-9.html"))
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(read(&dir, "9.ex").starts_with("0 3 6 9 12 15\n"));
+}
+
+#[test]
+fn page_without_examples() {
+    let dir = temp_dir("extract-empty");
+    let output = extract_page(&dir, "9", "../submit/correct.html");
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("no examples found"),
+        "{}",
+        stderr(&output)
+    );
+    assert!(!dir.join("examples").exists());
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use aoc2023::helpers::{parse_grid, polygon_area, transpose};
+use aoc2023::years::y2023::day11::{Day11, Day11Params};
+use aoc2023::{
+    profile_dir, Answer, AnswerStore, AocClient, AocError, InputManager, Overrides, Part, Solution,
+};
+use common::{example, example_inputs, temp_dir, FakeAoc, Response};
+
+#[test]
+fn registry() {
+    assert_eq!(aoc2023::solved_days(2023), (1..=25).collect::<Vec<_>>());
+    assert_eq!(aoc2023::latest(2020), Some(1));
+    assert_eq!(aoc2023::latest(2015), None);
+    assert!(aoc2023::solver(2023, 26).is_none());
+
+    let solver = aoc2023::solver(2023, 2).unwrap();
+    let solved = solver.solve(example(2023, 2).trim(), Part::Both, &Overrides::default());
+    assert_eq!(solved.part1, Some(Answer::UInt(8)));
+    assert_eq!(solved.part2, Some(Answer::UInt(2286)));
+}
+
+#[test]
+fn solutions_with_params() {
+    let input = example(2023, 11);
+    let grid = Day11::parse(input.trim());
+    let params = Day11Params {
+        expansion2: 10,
+        ..Default::default()
+    };
+    assert_eq!(Day11::part1(&grid, &params), Answer::UInt(374));
+    assert_eq!(Day11::part2(&grid, &params), Answer::UInt(1030));
+
+    let overrides = [("expansion2".to_string(), "100".to_string())]
+        .into_iter()
+        .collect::<Overrides>();
+    let solved = aoc2023::solve_day(2023, 11, input.trim(), Part::Two, &overrides).unwrap();
+    assert_eq!(solved.part1, None);
+    assert_eq!(solved.part2, Some(Answer::UInt(8410)));
+
+    let overrides = [("steps".to_string(), "6".to_string())]
+        .into_iter()
+        .collect::<Overrides>();
+    let err = aoc2023::solve_day(2023, 11, input.trim(), Part::Two, &overrides).unwrap_err();
+    assert!(
+        format!("{err:#}").contains("no parameter `steps`"),
+        "{err:#}"
+    );
+}
+
+#[test]
+fn input_manager() {
+    let dir = temp_dir("library");
+    example_inputs(&dir, 2023, &[2]);
+    let inputs = InputManager::new(&dir, true, AocClient::new("http://localhost:0"));
+    assert_eq!(inputs.get(2023, 2).unwrap(), example(2023, 2));
+    assert_eq!(inputs.missing(2023, &[1, 2, 3]), [1, 3]);
+    match inputs.get(2023, 3) {
+        Err(AocError::MissingInputs { year, days, .. }) => {
+            assert_eq!((year, days), (2023, vec![3]))
+        }
+        other => panic!("{other:?}"),
+    }
+}
+
+#[test]
+fn explicit_dirs_and_session() {
+    let dir = temp_dir("library-explicit");
+    std::fs::write(dir.join("2023.toml"), "[day2]\npart1 = \"8\"\n").unwrap();
+    let store = AnswerStore::open(&dir, 2023).unwrap();
+    assert_eq!(store.expected(2, 1), Some("8"));
+    assert_eq!(profile_dir(dir.clone(), Some("work")), dir.join("work"));
+    assert_eq!(profile_dir(dir.clone(), None), dir);
+
+    let server = FakeAoc::start(|_| Response::new(200, "1 2 3\n"));
+    let client = AocClient::new(&server.url).with_session("abc");
+    assert_eq!(client.download_input(2023, 1).unwrap(), "1 2 3\n");
+    assert_eq!(server.requests()[0].header("cookie"), Some("session=abc"));
+}
+
+#[test]
+fn helpers() {
+    let grid = parse_grid("\n  ab\n  cd\n  ef\n");
+    assert_eq!(grid, [['a', 'b'], ['c', 'd'], ['e', 'f']]);
+    assert_eq!(transpose(&grid), [['a', 'c', 'e'], ['b', 'd', 'f']]);
+    assert!(transpose::<char>(&[]).is_empty());
+    // a 3 by 3 square dug out along its edge covers 4 by 4 cells
+    let square = [(0, 0), (0, 3), (3, 3), (3, 0), (0, 0)];
+    assert_eq!(polygon_area(&square, 12), 16);
+}
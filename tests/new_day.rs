@@ -0,0 +1,95 @@
+mod common;
+
+use common::{aoc, stderr, stdout, temp_dir};
+use std::path::{Path, PathBuf};
+
+const EXAMPLE_TESTS: &str = "\
+mod common;
+
+example_tests! {
+    y2023 => 2023 {
+        day1 => 1, day11 => 11,
+    }
+}
+";
+
+/// A crate with only 2023 day 1 and day 11.
+fn project() -> PathBuf {
+    let root = temp_dir("new-day");
+    std::fs::create_dir_all(root.join("src/years/y2023")).unwrap();
+    std::fs::create_dir_all(root.join("tests")).unwrap();
+    std::fs::write(root.join("src/years/y2023/day1.rs"), "// day 1\n").unwrap();
+    std::fs::write(root.join("src/years/y2023/day11.rs"), "// day 11\n").unwrap();
+    std::fs::write(root.join("tests/examples.rs"), EXAMPLE_TESTS).unwrap();
+    root
+}
+
+fn new_day(root: &Path, year: &str, day: &str) -> std::process::Output {
+    aoc(root, "http://localhost:0")
+        .args(["new-day", "-y", year, "-d", day, "--root"])
+        .arg(root)
+        .output()
+        .unwrap()
+}
+
+fn read(root: &Path, path: &str) -> String {
+    std::fs::read_to_string(root.join(path)).unwrap()
+}
+
+#[test]
+fn scaffolds_a_day() {
+    let root = project();
+    let output = new_day(&root, "2023", "2");
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).contains("day2.rs"), "{}", stdout(&output));
+
+    let module = read(&root, "src/years/y2023/day2.rs");
+    assert!(module.contains("pub struct Day2;"), "{module}");
+    assert!(module.contains("impl Solution for Day2 {"), "{module}");
+    assert_eq!(
+        read(&root, "tests/examples.rs"),
+        EXAMPLE_TESTS.replace("day11 => 11,\n", "day11 => 11,\n        day2 => 2,\n")
+    );
+    assert_eq!(read(&root, "examples/2023/2.ex"), "");
+    assert_eq!(read(&root, "examples/2023/answers.toml"), "[\"2.ex\"]\n");
+}
+
+#[test]
+fn existing_day_is_not_overwritten() {
+    let root = project();
+    let output = new_day(&root, "2023", "1");
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("already exists"),
+        "{}",
+        stderr(&output)
+    );
+    assert_eq!(read(&root, "src/years/y2023/day1.rs"), "// day 1\n");
+    assert_eq!(read(&root, "tests/examples.rs"), EXAMPLE_TESTS);
+}
+
+#[test]
+fn existing_test_is_kept() {
+    let root = project();
+    std::fs::remove_file(root.join("src/years/y2023/day1.rs")).unwrap();
+    let output = new_day(&root, "2023", "1");
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(read(&root, "tests/examples.rs"), EXAMPLE_TESTS);
+}
+
+#[test]
+fn new_year() {
+    let root = project();
+    let output = new_day(&root, "2021", "4");
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(read(&root, "src/years/y2021/day4.rs").contains("pub struct Day4;"));
+    assert!(!root.join("src/years/y2023/day4.rs").exists());
+    assert_eq!(
+        read(&root, "tests/examples.rs"),
+        EXAMPLE_TESTS.replace(
+            "    }\n}",
+            "    }\n    y2021 => 2021 {\n        day4 => 4,\n    }\n}"
+        )
+    );
+    assert_eq!(read(&root, "examples/2021/4.ex"), "");
+}
@@ -0,0 +1,160 @@
+mod common;
+
+use common::{aoc, example, example_inputs, stderr, stdout, temp_dir};
+use std::path::PathBuf;
+
+/// The day 11 example as the real input, with its answers for the default parameters.
+fn inputs() -> PathBuf {
+    let dir = temp_dir("params");
+    example_inputs(&dir, 2023, &[11]);
+    std::fs::create_dir_all(dir.join("answers")).unwrap();
+    std::fs::write(
+        dir.join("answers/2023.toml"),
+        "[day11]\npart1 = \"374\"\npart2 = \"82000210\"\n",
+    )
+    .unwrap();
+    dir
+}
+
+#[test]
+fn flags_override_the_defaults() {
+    let dir = inputs();
+    let run = |args: &[&str]| {
+        let output = aoc(&dir, "http://localhost:0")
+            .args(["--offline", "run", "-d", "11"])
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", stderr(&output));
+        stdout(&output)
+    };
+    assert_eq!(run(&[]), "Day11: (374, 82000210)\n");
+    assert_eq!(
+        run(&["--param", "expansion2=10", "--param", "expansion1=1"]),
+        "Day11: (292, 1030)\n"
+    );
+    assert_eq!(
+        run(&["-p", "2", "--param", "expansion2=100"]),
+        "Day11: 8410\n"
+    );
+
+    // the answer store has the answers for the defaults
+    let json = run(&["--format", "json", "--param", "expansion2=10"]);
+    let records: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let verifications = records
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["verification"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(verifications, ["unchecked", "unchecked"]);
+}
+
+#[test]
+fn examples_declare_their_params() {
+    let dir = temp_dir("params-examples");
+    let examples = dir.join("examples/2023");
+    std::fs::create_dir_all(&examples).unwrap();
+    std::fs::write(examples.join("21.ex"), example(2023, 21)).unwrap();
+    std::fs::write(examples.join("21-2.ex"), example(2023, 21)).unwrap();
+    std::fs::write(
+        examples.join("answers.toml"),
+        r#"
+["21.ex"]
+part1 = "16"
+params = { steps1 = 6 }
+
+["21-2.ex"]
+part1 = "6"
+params = { steps1 = "3" }
+"#,
+    )
+    .unwrap();
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["examples", "-d", "21"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        stdout(&output),
+        "Day21 part 1 21.ex: PASS 16\n\
+         Day21 part 1 21-2.ex: PASS 6\n"
+    );
+
+    // one file checked with several parameter sets
+    std::fs::remove_file(examples.join("21-2.ex")).unwrap();
+    std::fs::write(
+        examples.join("answers.toml"),
+        r#"
+[["21.ex"]]
+part1 = "16"
+params = { steps1 = 6 }
+
+[["21.ex"]]
+part1 = "6"
+params = { steps1 = 3 }
+"#,
+    )
+    .unwrap();
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["examples", "-d", "21"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        stdout(&output),
+        "Day21 part 1 21.ex steps1=6: PASS 16\n\
+         Day21 part 1 21.ex steps1=3: PASS 6\n"
+    );
+
+    std::fs::write(
+        examples.join("answers.toml"),
+        "[\"21.ex\"]\npart1 = \"16\"\nparams = { steps = 6 }\n",
+    )
+    .unwrap();
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["examples", "-d", "21"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("no parameter `steps`, try steps1 (default 64)"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn invalid_params_are_errors() {
+    let dir = inputs();
+    example_inputs(&dir, 2023, &[1]);
+    let fails = |args: &[&str], message: &str| {
+        let output = aoc(&dir, "http://localhost:0")
+            .args(["--offline", "run"])
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "{}", stdout(&output));
+        assert!(stderr(&output).contains(message), "{}", stderr(&output));
+    };
+    fails(
+        &["-d", "11", "--param", "expansion=10"],
+        "2023 day 11: no parameter `expansion`, try expansion1 (default 2), expansion2 (default 1000000)",
+    );
+    fails(
+        &["-d", "11", "--param", "expansion2=ten"],
+        "invalid value `ten` for parameter `expansion2`",
+    );
+    fails(
+        &["-d", "11", "--param", "expansion2"],
+        "`expansion2` is not NAME=VALUE",
+    );
+    fails(
+        &["-d", "1", "--param", "expansion2=10"],
+        "2023 day 1: no parameter `expansion2`, the day has no parameters",
+    );
+    fails(
+        &["-d", "11-12", "--param", "expansion2=10"],
+        "--param can only be used when solving a single day",
+    );
+}
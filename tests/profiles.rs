@@ -0,0 +1,182 @@
+mod common;
+
+use common::{aoc, example_inputs, stderr, stdout, temp_dir, write, FakeAoc, Request, Response};
+
+const SETTINGS: &str = r#"<header><div><h1 class="title-global"><a href="/">Advent of Code</a></h1>
+<div class="user">alice <span class="star-count">50*</span></div></div></header>"#;
+
+/// Serves inputs and the settings page to `session=alice-session` and `session=bob-session`.
+fn website(request: &Request) -> Response {
+    let user = match request.header("cookie") {
+        Some("session=alice-session") => "alice",
+        Some("session=bob-session") => "bob",
+        _ => return Response::new(302, "").header("Location", "/auth/login"),
+    };
+    match request.path.as_str() {
+        "/settings" => Response::new(200, &SETTINGS.replace("alice", user)),
+        path => Response::new(200, &format!("{user} {path}\n")),
+    }
+}
+
+#[test]
+fn whoami_shows_the_account() {
+    let server = FakeAoc::start(website);
+    let dir = temp_dir("whoami");
+    let output = aoc(&dir, &server.url)
+        .env("AOC_SESSION", "alice-session")
+        .arg("whoami")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "alice (profile default)\n");
+    assert_eq!(server.requests()[0].path, "/settings");
+}
+
+#[test]
+fn whoami_rejects_an_expired_session() {
+    let server = FakeAoc::start(website);
+    let dir = temp_dir("whoami-expired");
+    let output = aoc(&dir, &server.url).arg("whoami").output().unwrap();
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("the session cookie is expired or invalid"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn profiles_have_their_own_session_and_input_cache() {
+    let server = FakeAoc::start(website);
+    let dir = temp_dir("profiles");
+    write(&dir.join("config/profiles/bob.session"), "bob-session\n");
+    let output = aoc(&dir, &server.url)
+        .env_remove("AOC_SESSION")
+        .args(["--profile", "bob", "whoami"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "bob (profile bob)\n");
+
+    let output = aoc(&dir, &server.url)
+        .env_remove("AOC_SESSION")
+        .env("AOC_PROFILE", "bob")
+        .args(["fetch", "-d", "4"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        std::fs::read_to_string(dir.join("bob/2023-4.in")).unwrap(),
+        "bob /2023/day/4/input\n"
+    );
+    assert!(!dir.join("2023-4.in").exists());
+}
+
+#[test]
+fn missing_profile_session() {
+    let server = FakeAoc::start(website);
+    let dir = temp_dir("profile-missing");
+    let output = aoc(&dir, &server.url)
+        .env_remove("AOC_SESSION")
+        .args(["--profile", "carol", "fetch", "-d", "4"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let expected = dir.join("config/profiles/carol.session");
+    assert!(
+        stderr(&output).contains(&format!(
+            "no session found, set AOC_SESSION or put it in {}",
+            expected.display()
+        )),
+        "{}",
+        stderr(&output)
+    );
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn invalid_profile_names_are_rejected() {
+    let dir = temp_dir("profile-invalid");
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["--profile", "../bob", "whoami"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("isn't a profile name"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn profiles_have_their_own_answer_store() {
+    let dir = temp_dir("profile-answers");
+    for profile in ["alice", "bob"] {
+        example_inputs(&dir.join(profile), 2023, &[1]);
+    }
+    write(
+        &dir.join("answers/alice/2023.toml"),
+        "[day1]\npart1 = \"1\"\n",
+    );
+    let output = aoc(&dir, "http://localhost:0")
+        .args([
+            "--offline",
+            "--profile",
+            "bob",
+            "verify",
+            "-d",
+            "1",
+            "--record",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    let recorded = std::fs::read_to_string(dir.join("answers/bob/2023.toml")).unwrap();
+    assert!(recorded.contains("part1 = \"142\""), "{recorded}");
+    assert_eq!(
+        std::fs::read_to_string(dir.join("answers/alice/2023.toml")).unwrap(),
+        "[day1]\npart1 = \"1\"\n"
+    );
+
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["--offline", "--profile", "alice", "verify", "-d", "1"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(
+        stdout(&output).contains("Day1 part 1: FAIL"),
+        "{}",
+        stdout(&output)
+    );
+}
+
+#[test]
+fn big_fetches_check_the_session_first() {
+    let server = FakeAoc::start(website);
+    let dir = temp_dir("fetch-whoami");
+    let output = aoc(&dir, &server.url)
+        .args(["fetch", "-d", "1-5"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let paths = server
+        .requests()
+        .into_iter()
+        .map(|r| r.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, ["/settings"]);
+
+    let output = aoc(&dir, &server.url)
+        .env("AOC_SESSION", "alice-session")
+        .args(["fetch", "-d", "1-3"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(
+        stderr(&output).contains("downloading 3 inputs as alice"),
+        "{}",
+        stderr(&output)
+    );
+    assert_eq!(server.requests().len(), 5);
+}
@@ -0,0 +1,433 @@
+mod common;
+
+use common::{aoc, example, example_inputs, stderr, stdout, temp_dir};
+use std::path::PathBuf;
+
+/// Real inputs for 2023 day 1 and 2 (the examples), with answers for day 1.
+fn inputs() -> PathBuf {
+    let dir = temp_dir("run");
+    example_inputs(&dir, 2023, &[1, 2]);
+    std::fs::create_dir_all(dir.join("answers")).unwrap();
+    std::fs::write(
+        dir.join("answers/2023.toml"),
+        "[day1]\npart1 = \"142\"\npart2 = \"281\"\n",
+    )
+    .unwrap();
+    dir
+}
+
+/// The table without the runtime column, which changes from run to run.
+fn cells(output: &str) -> Vec<Vec<String>> {
+    output
+        .lines()
+        .map(|line| {
+            let mut cells = line.split("  ").map(str::trim).filter(|c| !c.is_empty());
+            let row = cells
+                .by_ref()
+                .take(3)
+                .map(str::to_string)
+                .collect::<Vec<_>>();
+            row.into_iter()
+                .chain(cells.skip(1).map(str::to_string))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn several_days_print_a_table_in_day_order() {
+    let dir = inputs();
+    for jobs in ["1", "2", "0"] {
+        let output = aoc(&dir, "http://localhost:0")
+            .args(["--offline", "run", "-d", "1-2", "-j", jobs])
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", stderr(&output));
+        assert_eq!(
+            cells(&stdout(&output)),
+            [
+                ["day", "part 1", "part 2", "verification"],
+                ["1", "142", "142", "PASS / FAIL"],
+                ["2", "8", "2286", "UNKNOWN / UNKNOWN"],
+            ]
+        );
+    }
+}
+
+#[test]
+fn examples_are_not_verified() {
+    let dir = inputs();
+    std::fs::create_dir_all(dir.join("examples/2023")).unwrap();
+    for day in [1, 2] {
+        std::fs::rename(
+            dir.join(format!("2023-{day}.in")),
+            dir.join(format!("examples/2023/{day}.ex")),
+        )
+        .unwrap();
+    }
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["--offline", "run", "-d", "1-2", "-e", "-p", "1"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        cells(&stdout(&output)),
+        [
+            ["day", "part 1", "part 2", "verification"],
+            ["1", "142", "-", "- / -"],
+            ["2", "8", "-", "- / -"],
+        ]
+    );
+}
+
+#[test]
+fn single_day_prints_one_line() {
+    let dir = inputs();
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["--offline", "run", "-d", "2", "-j", "4"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "Day2: (8, 2286)\n");
+}
+
+fn run(dir: &std::path::Path, args: &[&str]) -> String {
+    let output = aoc(dir, "http://localhost:0")
+        .arg("--offline")
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    stdout(&output)
+}
+
+#[test]
+fn json_schema() {
+    let dir = inputs();
+    let output = run(&dir, &["run", "-d", "1-2", "-j", "2", "--format", "json"]);
+    let records: Vec<serde_json::Map<String, serde_json::Value>> =
+        serde_json::from_str(&output).unwrap();
+    let keys = [
+        "year",
+        "day",
+        "part",
+        "answer",
+        "parse_us",
+        "part_us",
+        "verification",
+        "expected",
+        "error",
+    ];
+    // fields come in schema order
+    let positions = keys.map(|key| output.find(&format!("\"{key}\":")).unwrap());
+    assert!(positions.is_sorted(), "{output}");
+    let mut sorted = keys;
+    sorted.sort();
+    for record in &records {
+        assert_eq!(record.keys().collect::<Vec<_>>(), sorted, "{record:?}");
+        assert!(record["parse_us"].is_u64() && record["part_us"].is_u64());
+    }
+    let without_timings = records
+        .iter()
+        .map(|r| {
+            let mut r = r.clone();
+            r.remove("parse_us");
+            r.remove("part_us");
+            serde_json::Value::Object(r)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        serde_json::Value::Array(without_timings),
+        serde_json::json!([
+            {"year": 2023, "day": 1, "part": 1, "answer": "142", "verification": "pass", "expected": null, "error": null},
+            {"year": 2023, "day": 1, "part": 2, "answer": "142", "verification": "fail", "expected": "281", "error": null},
+            {"year": 2023, "day": 2, "part": 1, "answer": "8", "verification": "unknown", "expected": null, "error": null},
+            {"year": 2023, "day": 2, "part": 2, "answer": "2286", "verification": "unknown", "expected": null, "error": null},
+        ])
+    );
+}
+
+/// CSV rows without the timing columns.
+fn csv_without_timings(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .map(|line| {
+            let cells = line.split(',').collect::<Vec<_>>();
+            [&cells[..4], &cells[6..]].concat().join(",")
+        })
+        .collect()
+}
+
+#[test]
+fn csv_schema() {
+    let dir = inputs();
+    let output = run(&dir, &["run", "-d", "1-2", "--format", "csv"]);
+    assert_eq!(
+        output.lines().next(),
+        Some("year,day,part,answer,parse_us,part_us,verification,expected,error")
+    );
+    for line in output.lines().skip(1) {
+        let cells = line.split(',').collect::<Vec<_>>();
+        assert!(cells[4].parse::<u64>().is_ok() && cells[5].parse::<u64>().is_ok());
+    }
+    assert_eq!(
+        csv_without_timings(&output),
+        [
+            "year,day,part,answer,verification,expected,error",
+            "2023,1,1,142,pass,,",
+            "2023,1,2,142,fail,281,",
+            "2023,2,1,8,unknown,,",
+            "2023,2,2,2286,unknown,,",
+        ]
+    );
+}
+
+#[test]
+fn input_files_are_unchecked() {
+    let dir = inputs();
+    let input = dir.join("2023-1.in");
+    let output = run(
+        &dir,
+        &[
+            "run",
+            "-d",
+            "1",
+            "-p",
+            "1",
+            "--format",
+            "csv",
+            "-i",
+            input.to_str().unwrap(),
+        ],
+    );
+    assert_eq!(
+        csv_without_timings(&output),
+        [
+            "year,day,part,answer,verification,expected,error",
+            "2023,1,1,142,unchecked,,",
+        ]
+    );
+}
+
+/// Day 20 part 2 never finds the conjunction feeding `rx` firing on this input.
+const ENDLESS_DAY20: &str = "broadcaster -> a\n%a -> out\n&inv -> con\n&con -> rx\n";
+
+fn with_endless_day() -> PathBuf {
+    let dir = inputs();
+    std::fs::write(dir.join("2023-20.in"), ENDLESS_DAY20).unwrap();
+    dir
+}
+
+#[test]
+fn parts_that_run_too_long_time_out() {
+    let dir = with_endless_day();
+    let output = run(&dir, &["run", "-d", "20", "--timeout", "0.2"]);
+    assert_eq!(output, "Day20: (1250000, TIMEOUT)\n");
+
+    let output = run(
+        &dir,
+        &["run", "-d", "20", "--timeout", "0.2", "--format", "csv"],
+    );
+    assert_eq!(
+        csv_without_timings(&output),
+        [
+            "year,day,part,answer,verification,expected,error",
+            "2023,20,1,1250000,unknown,,",
+            "2023,20,2,,timeout,,",
+        ]
+    );
+    let timeout = output.lines().last().unwrap().split(',').nth(5).unwrap();
+    assert_eq!(timeout, "200000");
+}
+
+#[test]
+fn other_days_keep_running_after_a_timeout() {
+    let dir = with_endless_day();
+    example_inputs(&dir, 2023, &[19]);
+    // with one thread the stuck part takes it, the other day gets a new one
+    for jobs in ["1", "2"] {
+        let output = run(
+            &dir,
+            &["run", "-d", "19-20", "-j", jobs, "--timeout", "0.2"],
+        );
+        assert_eq!(
+            cells(&output),
+            [
+                ["day", "part 1", "part 2", "verification"],
+                ["19", "19114", "167409079868000", "UNKNOWN / UNKNOWN"],
+                ["20", "1250000", "TIMEOUT", "UNKNOWN / TIMEOUT"],
+            ]
+        );
+    }
+
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["--offline", "verify", "-d", "19-20", "--timeout", "0.2"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(
+        stdout(&output).contains("Day20 part 2: TIMEOUT\n"),
+        "{}",
+        stdout(&output)
+    );
+    assert!(
+        stdout(&output).contains("Day19 part 2: UNKNOWN"),
+        "{}",
+        stdout(&output)
+    );
+    assert!(
+        stderr(&output).contains("1 part(s) timed out"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+/// Day 5 with the maps of its example and seed ranges that keep `par_iter` busy for a while.
+fn slow_day5() -> String {
+    let example = example(2023, 5);
+    let (_, maps) = example.split_once("\n\n").unwrap();
+    let seeds = (0..8)
+        .map(|i| format!("{} 40000", i * 1_000_000))
+        .collect::<Vec<_>>();
+    format!("seeds: {}\n\n{maps}", seeds.join(" "))
+}
+
+#[test]
+fn waiting_for_a_thread_does_not_count_towards_the_timeout() {
+    let dir = inputs();
+    std::fs::write(dir.join("2023-5.in"), slow_day5()).unwrap();
+    example_inputs(&dir, 2023, &[6]);
+    // the threads steal day 5's parallel work before they pick up day 6
+    for _ in 0..3 {
+        let output = run(&dir, &["run", "-d", "5-6", "-j", "2", "--timeout", "0.5"]);
+        let cells = cells(&output);
+        assert_eq!(cells[1][..2], ["5", "22"], "{output}");
+        assert_eq!(
+            cells[2],
+            ["6", "288", "71503", "UNKNOWN / UNKNOWN"],
+            "{output}"
+        );
+    }
+}
+
+/// Day 20 part 2 unwraps the module feeding `rx`, which this input doesn't have.
+const PANICKING_DAY20: &str = "broadcaster -> a\n%a -> out\n";
+
+#[test]
+fn panicking_days_fail_on_their_own() {
+    let dir = inputs();
+    std::fs::write(dir.join("2023-20.in"), PANICKING_DAY20).unwrap();
+    example_inputs(&dir, 2023, &[19]);
+    let location = "src/years/y2023/day20.rs:";
+    let message = "called `Option::unwrap()` on a `None` value";
+
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["--offline", "run", "-d", "19-20", "-j", "2"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert_eq!(
+        cells(&stdout(&output)),
+        [
+            ["day", "part 1", "part 2", "verification"],
+            ["19", "19114", "167409079868000", "UNKNOWN / UNKNOWN"],
+            ["20", "1250000", "FAILED", "UNKNOWN / FAILED"],
+        ]
+    );
+    let errors = stderr(&output);
+    assert!(
+        errors.starts_with("error: day 20 part 2 panicked at "),
+        "{errors}"
+    );
+    assert!(
+        errors.contains(location) && errors.contains(message),
+        "{errors}"
+    );
+    assert!(errors.ends_with("error: 1 day(s) failed\n"), "{errors}");
+    assert!(!errors.contains("stack backtrace"), "{errors}");
+
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["--offline", "run", "-d", "20", "--format", "csv"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let rows = stdout(&output)
+        .lines()
+        .skip(1)
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    assert!(rows[0].starts_with("2023,20,1,1250000,"), "{rows:?}");
+    assert!(rows[0].ends_with(",unknown,,"), "{rows:?}");
+    assert!(rows[1].starts_with("2023,20,2,,"), "{rows:?}");
+    assert!(rows[1].contains(",failed,,panicked at "), "{rows:?}");
+    assert!(
+        rows[1].contains(location) && rows[1].ends_with(message),
+        "{rows:?}"
+    );
+
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["--offline", "verify", "-d", "19-20", "--timeout", "5"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(
+        stdout(&output).contains("Day19 part 2: UNKNOWN"),
+        "{}",
+        stdout(&output)
+    );
+    assert!(
+        stdout(&output).contains("Day20 part 1: UNKNOWN 1250000"),
+        "{}",
+        stdout(&output)
+    );
+    assert!(
+        stdout(&output).contains("Day20 part 2: FAILED panicked at "),
+        "{}",
+        stdout(&output)
+    );
+    assert!(
+        stderr(&output).contains("1 part(s) failed"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn run_flags_before_a_subcommand_are_rejected() {
+    let dir = inputs();
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["-y", "2020", "-d", "1", "examples", "-d", "2"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success(), "{}", stdout(&output));
+    assert!(
+        stderr(&output).contains("--year can't be used with `examples`"),
+        "{}",
+        stderr(&output)
+    );
+
+    // global flags are fine in front of a subcommand
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["--offline", "run", "-d", "1"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "Day1: (142, 142)\n");
+}
+
+#[test]
+fn solver_output_stays_out_of_json() {
+    let dir = inputs();
+    example_inputs(&dir, 2023, &[5]);
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["--offline", "run", "-d", "5", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    let records: Vec<serde_json::Value> = serde_json::from_str(&stdout(&output)).unwrap();
+    let answers = records
+        .iter()
+        .map(|r| r["answer"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(answers, ["35", "46"]);
+}
@@ -0,0 +1,229 @@
+mod common;
+
+use common::{aoc, fixture, stderr, stdout, temp_dir, FakeAoc, Response};
+use std::path::Path;
+
+const DAY4_EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 19 24
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+fn submit(dir: &Path, server: &FakeAoc, args: &[&str]) -> std::process::Output {
+    aoc(dir, &server.url)
+        .args(["submit", "-d", "4"])
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn serve(page: &str) -> FakeAoc {
+    let page = fixture(page);
+    FakeAoc::start(move |_| Response::new(200, &page))
+}
+
+#[test]
+fn correct_answer_is_posted_and_recorded() {
+    let server = serve("submit/correct.html");
+    let dir = temp_dir("submit-correct");
+    let output = submit(&dir, &server, &["-p", "1", "13"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(
+        stdout(&output).contains("13 is correct"),
+        "{}",
+        stdout(&output)
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2023/day/4/answer");
+    assert_eq!(requests[0].header("cookie"), Some("session=test-session"));
+    assert_eq!(requests[0].body, "level=1&answer=13");
+    let answers = std::fs::read_to_string(dir.join("answers/2023.toml")).unwrap();
+    assert!(answers.contains("part1 = \"13\""), "{answers}");
+
+    // known correct answers aren't submitted again
+    let output = submit(&dir, &server, &["-p", "1", "13"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn answer_defaults_to_the_solution() {
+    let server = serve("submit/correct.html");
+    let dir = temp_dir("submit-solve");
+    std::fs::write(dir.join("2023-4.in"), DAY4_EXAMPLE).unwrap();
+    let output = submit(&dir, &server, &["-p", "2"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(server.requests()[0].body, "level=2&answer=30");
+}
+
+fn assert_wrong_answer(page: &str, expected: &str) {
+    let server = serve(page);
+    let dir = temp_dir("submit-wrong");
+    let output = submit(&dir, &server, &["-p", "2", "42"]);
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains(expected), "{}", stdout(&output));
+    assert!(!dir.join("answers/2023.toml").exists());
+
+    // the same wrong answer is never submitted twice
+    let output = submit(&dir, &server, &["-p", "2", "42"]);
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("not submitting 42"),
+        "{}",
+        stderr(&output)
+    );
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn too_high() {
+    assert_wrong_answer("submit/too_high.html", "42 is incorrect, too high");
+}
+
+#[test]
+fn too_low() {
+    assert_wrong_answer("submit/too_low.html", "42 is incorrect, too low");
+}
+
+#[test]
+fn incorrect_without_hint() {
+    assert_wrong_answer("submit/incorrect.html", "42 is incorrect");
+}
+
+#[test]
+fn already_solved() {
+    let server = serve("submit/already_solved.html");
+    let dir = temp_dir("submit-solved");
+    let output = submit(&dir, &server, &["-p", "1", "13"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(
+        stdout(&output).contains("already solved"),
+        "{}",
+        stdout(&output)
+    );
+    assert!(!dir.join("answers").exists());
+}
+
+#[test]
+fn rate_limited_shows_wait_time_and_can_be_retried() {
+    let server = serve("submit/rate_limited.html");
+    let dir = temp_dir("submit-rate-limited");
+    for _ in 0..2 {
+        let output = submit(&dir, &server, &["-p", "1", "13"]);
+        assert!(!output.status.success());
+        assert!(
+            stdout(&output).contains("rate limited, wait 95s"),
+            "{}",
+            stdout(&output)
+        );
+    }
+    assert_eq!(server.requests().len(), 2);
+    assert!(!dir.join("answers").exists());
+}
+
+#[test]
+fn offline_mode_never_submits() {
+    let server = serve("submit/correct.html");
+    let dir = temp_dir("submit-offline");
+    let output = submit(&dir, &server, &["--offline", "-p", "1", "13"]);
+    assert!(!output.status.success());
+    assert!(server.requests().is_empty());
+}
+
+fn write_submissions(dir: &Path, log: &str) {
+    std::fs::create_dir_all(dir.join("answers")).unwrap();
+    std::fs::write(dir.join("answers/2023-submissions.toml"), log).unwrap();
+}
+
+#[test]
+fn answers_outside_known_bounds_are_not_submitted() {
+    let server = serve("submit/correct.html");
+    let dir = temp_dir("submit-bounds");
+    write_submissions(
+        &dir,
+        r#"
+[[day4.part1]]
+answer = "20"
+correct = false
+hint = "too_high"
+
+[[day4.part1]]
+answer = "5"
+correct = false
+hint = "too_low"
+"#,
+    );
+    for (answer, expected) in [
+        ("25", "is too high, 20 already was"),
+        ("3", "is too low, 5 already was"),
+    ] {
+        let output = submit(&dir, &server, &["-p", "1", answer]);
+        assert!(!output.status.success());
+        assert!(stderr(&output).contains(expected), "{}", stderr(&output));
+    }
+    assert!(server.requests().is_empty());
+
+    let output = submit(&dir, &server, &["-p", "1", "13"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn run_warns_about_known_wrong_answers() {
+    let server = serve("submit/correct.html");
+    let dir = temp_dir("run-known-wrong");
+    std::fs::write(dir.join("2023-4.in"), DAY4_EXAMPLE).unwrap();
+    write_submissions(
+        &dir,
+        r#"
+[[day4.part2]]
+answer = "25"
+correct = false
+hint = "too_high"
+"#,
+    );
+    let output = aoc(&dir, &server.url)
+        .args(["run", "-d", "4", "-p", "2"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "Day4: 30\n");
+    assert!(
+        stderr(&output).contains("warning: day 4 part 2: 30 is too high, 25 already was"),
+        "{}",
+        stderr(&output)
+    );
+
+    write_submissions(
+        &dir,
+        r#"
+[[day4.part2]]
+answer = "30"
+correct = false
+"#,
+    );
+    let output = aoc(&dir, &server.url)
+        .args(["run", "-d", "4", "-p", "2"])
+        .output()
+        .unwrap();
+    assert!(
+        stderr(&output).contains("warning: day 4 part 2: 30 was already rejected"),
+        "{}",
+        stderr(&output)
+    );
+
+    // examples were never submitted, their answers aren't checked
+    std::fs::create_dir_all(dir.join("examples/2023")).unwrap();
+    std::fs::write(dir.join("examples/2023/4.ex"), DAY4_EXAMPLE).unwrap();
+    let output = aoc(&dir, &server.url)
+        .args(["run", "-d", "4", "-p", "2", "-e"])
+        .output()
+        .unwrap();
+    assert!(!stderr(&output).contains("warning"), "{}", stderr(&output));
+}
@@ -0,0 +1,194 @@
+mod common;
+
+use aoc2023::clock::{self, Clock, FakeClock};
+use aoc2023::{AocClient, InputManager};
+use common::{aoc, example, example_store, stderr, stdout, temp_dir, FakeAoc, Response};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+/// Examples of 2023 day 4 and 5, the website serves the examples as the real inputs.
+fn project() -> PathBuf {
+    let dir = temp_dir("today");
+    example_store(&dir, 2023, &[4, 5]);
+    dir
+}
+
+fn inputs(request: &common::Request) -> Response {
+    match request.path.as_str() {
+        "/2023/day/4/input" => Response::new(200, &example(2023, 4)),
+        "/2023/day/5/input" => Response::new(200, &example(2023, 5)),
+        "/2024/day/1/input" => Response::new(200, "1 2\n"),
+        _ => Response::new(404, "not found"),
+    }
+}
+
+/// `seconds` before a puzzle unlocks.
+fn before_unlock(year: u32, day: u32, seconds: u64) -> SystemTime {
+    clock::unlock_time(year, day) - Duration::from_secs(seconds)
+}
+
+/// The puzzle `today` picks at `now`, with the countdown it printed.
+fn todays_puzzle(
+    now: SystemTime,
+    puzzle: Option<(u32, u32)>,
+    wait: bool,
+) -> (anyhow::Result<(u32, u32)>, String) {
+    let mut countdown = Vec::new();
+    let puzzle = clock::todays_puzzle(&FakeClock::new(now), puzzle, wait, &mut countdown);
+    (puzzle, String::from_utf8(countdown).unwrap())
+}
+
+fn today(dir: &Path, server: &FakeAoc, args: &[&str]) -> std::process::Output {
+    aoc(dir, &server.url)
+        .arg("today")
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn picks_the_puzzle_of_today() {
+    // just before day 5 unlocks, it's still day 4 in US Eastern time
+    let (puzzle, countdown) = todays_puzzle(before_unlock(2023, 5, 2), None, false);
+    assert_eq!(puzzle.unwrap(), (2023, 4));
+    assert_eq!(countdown, "");
+}
+
+#[test]
+fn waits_for_the_next_puzzle() {
+    let now = before_unlock(2023, 5, 2);
+    let clock = FakeClock::new(now);
+    let mut countdown = Vec::new();
+    let puzzle = clock::todays_puzzle(&clock, None, true, &mut countdown).unwrap();
+    assert_eq!(puzzle, (2023, 5));
+    assert_eq!(clock.now(), clock::unlock_time(2023, 5));
+    let countdown = String::from_utf8(countdown).unwrap();
+    assert!(
+        countdown.starts_with(
+            "\r2023 day 5 unlocks in 00:00:02\r2023 day 5 unlocks in 00:00:01\r2023 day 5 is unlocked"
+        ),
+        "{countdown:?}"
+    );
+
+    // still November in US Eastern time
+    let (puzzle, countdown) = todays_puzzle(before_unlock(2023, 1, 2), None, true);
+    assert_eq!(puzzle.unwrap(), (2023, 1));
+    assert!(
+        countdown.starts_with("\r2023 day 1 unlocks in 00:00:02"),
+        "{countdown:?}"
+    );
+}
+
+#[test]
+fn no_puzzle_outside_of_the_event() {
+    let ten_days = 10 * 86400 + 16 * 3600 + 30 * 60;
+    let (puzzle, _) = todays_puzzle(before_unlock(2023, 1, ten_days), None, false);
+    assert_eq!(
+        puzzle.unwrap_err().to_string(),
+        "no puzzle today, 2023 day 1 unlocks in 10d 16:30:00, --wait waits for it"
+    );
+
+    let (puzzle, _) = todays_puzzle(clock::unlock_time(2023, 26), None, false);
+    assert!(puzzle
+        .unwrap_err()
+        .to_string()
+        .contains("2024 day 1 unlocks in 341d 00:00:00"));
+}
+
+#[test]
+fn given_puzzles_are_waited_for_too() {
+    let now = before_unlock(2023, 6, 60);
+    let (puzzle, countdown) = todays_puzzle(now, Some((2023, 4)), false);
+    assert_eq!(puzzle.unwrap(), (2023, 4));
+    assert_eq!(countdown, "");
+
+    let (puzzle, _) = todays_puzzle(now, Some((2023, 6)), false);
+    assert_eq!(
+        puzzle.unwrap_err().to_string(),
+        "2023 day 6 unlocks in 00:01:00, --wait waits for it"
+    );
+    let (puzzle, countdown) = todays_puzzle(now, Some((2023, 6)), true);
+    assert_eq!(puzzle.unwrap(), (2023, 6));
+    assert!(
+        countdown.starts_with("\r2023 day 6 unlocks in 00:01:00\r2023 day 6 unlocks in 00:00:59"),
+        "{countdown:?}"
+    );
+}
+
+#[test]
+fn retries_until_the_website_unlocks_the_input() {
+    let dir = temp_dir("today-retries");
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let counted = attempts.clone();
+    let server = FakeAoc::start(
+        move |request| match counted.fetch_add(1, Ordering::SeqCst) {
+            0 | 1 => Response::new(404, "not found"),
+            _ => inputs(request),
+        },
+    );
+    let client = AocClient::new(&server.url).with_session("test-session");
+    let inputs = InputManager::new(&dir, false, client);
+    let clock = FakeClock::new(clock::unlock_time(2023, 5));
+    inputs.fetch_unlocked(&clock, 2023, 5).unwrap();
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    assert_eq!(
+        clock.now(),
+        clock::unlock_time(2023, 5) + Duration::from_secs(2)
+    );
+    assert_eq!(inputs.get(2023, 5).unwrap(), example(2023, 5));
+}
+
+#[test]
+fn solves_the_puzzle() {
+    let dir = project();
+    let server = FakeAoc::start(inputs);
+    let output = today(&dir, &server, &["--puzzle", "2023-4"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        stdout(&output),
+        "2023 day 4\n\
+         Day4 part 1 4.ex: PASS 13\n\
+         Day4 part 2 4.ex: PASS 30\n\
+         Day4: (13, 30)\n"
+    );
+    let paths = server
+        .requests()
+        .into_iter()
+        .map(|r| r.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, ["/2023/day/4/input"]);
+}
+
+#[test]
+fn fetches_the_input_of_a_day_without_a_solution() {
+    let dir = project();
+    let server = FakeAoc::start(inputs);
+    let output = today(&dir, &server, &["--puzzle", "2024-1"]);
+    assert!(!output.status.success());
+    assert_eq!(stdout(&output), "2024 day 1\n");
+    assert!(
+        stderr(&output).contains("no solution for 2024 day 1 yet"),
+        "{}",
+        stderr(&output)
+    );
+    assert_eq!(
+        std::fs::read_to_string(dir.join("2024-1.in")).unwrap(),
+        "1 2\n"
+    );
+}
+
+#[test]
+fn invalid_puzzles_are_rejected() {
+    let dir = project();
+    let server = FakeAoc::start(inputs);
+    let output = today(&dir, &server, &["--puzzle", "2023-26"]);
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("`2023-26` isn't a puzzle like 2023-5"),
+        "{}",
+        stderr(&output)
+    );
+    assert!(server.requests().is_empty());
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use common::{aoc, example_inputs, example_store, temp_dir};
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::process::{Child, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+struct Watch {
+    child: Child,
+    lines: Receiver<String>,
+}
+
+impl Watch {
+    fn start(dir: &Path) -> Self {
+        let mut child = aoc(dir, "http://localhost:0")
+            .args(["--offline", "watch", "-d", "2", "--interval", "20"])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap();
+        let stdout = BufReader::new(child.stdout.take().unwrap());
+        let (send, lines) = mpsc::channel();
+        std::thread::spawn(move || {
+            for line in stdout.lines() {
+                let Ok(line) = line else { break };
+                if send.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        Self { child, lines }
+    }
+
+    /// The lines printed until watch goes back to waiting for changes.
+    fn result(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        loop {
+            let line = self.lines.recv_timeout(Duration::from_secs(30)).unwrap();
+            if line.starts_with("watching ") {
+                return lines;
+            }
+            lines.push(line);
+        }
+    }
+}
+
+impl Drop for Watch {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// 2023 day 2 with its example, and the example as the real input.
+fn project() -> std::path::PathBuf {
+    let dir = temp_dir("watch");
+    example_store(&dir, 2023, &[2]);
+    example_inputs(&dir, 2023, &[2]);
+    dir
+}
+
+const GAME: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n";
+
+#[test]
+fn reruns_when_the_input_changes() {
+    let dir = project();
+    let watch = Watch::start(&dir);
+    assert_eq!(
+        watch.result(),
+        [
+            "Day2 part 1 2.ex: PASS 8",
+            "Day2 part 2 2.ex: PASS 2286",
+            "Day2: (8, 2286)",
+        ]
+    );
+
+    std::fs::write(dir.join("2023-2.in"), GAME).unwrap();
+    assert_eq!(
+        watch.result(),
+        [
+            "  Day2 part 1 2.ex: PASS 8",
+            "  Day2 part 2 2.ex: PASS 2286",
+            "- Day2: (8, 2286)",
+            "+ Day2: (1, 48)",
+        ]
+    );
+}
+
+#[test]
+fn real_input_waits_for_the_examples() {
+    let dir = project();
+    let watch = Watch::start(&dir);
+    watch.result();
+
+    std::fs::write(
+        dir.join("examples/2023/answers.toml"),
+        "[\"2.ex\"]\npart1 = \"9\"\npart2 = \"2286\"\n",
+    )
+    .unwrap();
+    let result = watch.result();
+    assert!(
+        result.contains(&"- Day2: (8, 2286)".to_string()),
+        "{result:?}"
+    );
+    assert!(
+        result.contains(&"+ skipping the real input until the examples pass".to_string()),
+        "{result:?}"
+    );
+    assert!(
+        result
+            .iter()
+            .any(|line| line.starts_with("+ Day2 part 1 2.ex: FAIL")),
+        "{result:?}"
+    );
+
+    // a new example file is picked up as well
+    std::fs::write(dir.join("examples/2023/answers.toml"), "").unwrap();
+    std::fs::write(dir.join("examples/2023/2-2.ex"), GAME).unwrap();
+    let result = watch.result();
+    assert!(
+        result.contains(&"+ Day2: (8, 2286)".to_string()),
+        "{result:?}"
+    );
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use common::{aoc, stderr, stdout, temp_dir};
+
+#[test]
+fn inputs_are_kept_per_year() {
+    let dir = temp_dir("years-run");
+    std::fs::write(dir.join("2020-1.in"), "1721\n979\n366\n299\n675\n1456\n").unwrap();
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["--offline", "run", "-y", "2020", "-d", "1"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "Day1: (514579, 241861950)\n");
+
+    // 2023 day 1 has its own input
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["--offline", "run", "-y", "2023", "-d", "1"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("2023 day 1"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn missing_year() {
+    let dir = temp_dir("years-missing");
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["--offline", "run", "-y", "2016"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("no solutions for 2016"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn ranges_skip_days_without_a_solution() {
+    let dir = temp_dir("years-gap");
+    std::fs::write(dir.join("2020-1.in"), "1721\n979\n366\n299\n675\n1456\n").unwrap();
+    std::fs::create_dir_all(dir.join("answers")).unwrap();
+    std::fs::write(
+        dir.join("answers/2020.toml"),
+        "[day1]\npart1 = \"514579\"\npart2 = \"241861950\"\n",
+    )
+    .unwrap();
+    // offline, so days 2 and 3 would fail on their missing inputs
+    for args in [
+        &["run", "-y", "2020", "-d", "1-3"][..],
+        &["verify", "-y", "2020", "-d", "1-3"],
+        &["bench", "-y", "2020", "-d", "1-3", "--runs", "1"],
+    ] {
+        let output = aoc(&dir, "http://localhost:0")
+            .arg("--offline")
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{args:?}: {}", stderr(&output));
+        assert!(!stdout(&output).contains("Day2"), "{}", stdout(&output));
+    }
+    let output = aoc(&dir, "http://localhost:0")
+        .args(["--offline", "run", "-y", "2020", "-d", "2-3"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("no solutions for the selected days of 2020"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn summary_across_years() {
+    let dir = temp_dir("years-summary");
+    std::fs::write(dir.join("2020-1.in"), "1721\n979\n366\n299\n675\n1456\n").unwrap();
+    std::fs::write(dir.join("2023-1.in"), "1abc2\n").unwrap();
+    std::fs::write(dir.join("2023-2.in"), "Game 1: 3 blue\n").unwrap();
+    std::fs::create_dir_all(dir.join("answers")).unwrap();
+    std::fs::write(
+        dir.join("answers/2020.toml"),
+        "[day1]\npart1 = \"514579\"\npart2 = \"241861950\"\n",
+    )
+    .unwrap();
+    std::fs::write(dir.join("answers/2023.toml"), "[day1]\npart1 = \"12\"\n").unwrap();
+    std::fs::create_dir_all(dir.join("examples/2023")).unwrap();
+    std::fs::write(dir.join("examples/2023/1.ex"), "1abc2\n").unwrap();
+    std::fs::write(dir.join("examples/2023/1-2.ex"), "two1nine\n").unwrap();
+
+    let output = aoc(&dir, "http://localhost:0")
+        .arg("summary")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        stdout(&output),
+        "year   solved days  stars  inputs  examples\n\
+         2020   1            2/2    1/1     0/1\n\
+         2023   25           1/50   2/25    1/25\n\
+         total  26           3/52   3/26    1/26\n"
+    );
+}